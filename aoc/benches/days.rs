@@ -0,0 +1,112 @@
+//! Parse and solve timings for every day, on the puzzle example and on a
+//! synthetic input larger than the real one.
+//!
+//! Criterion keeps each run under `target/criterion` and reports the change
+//! against the previous one; use `cargo bench -- --save-baseline <name>` and
+//! `--baseline <name>` to compare against a named run, and a day's group name
+//! (e.g. `cargo bench -- day-8`) to run just that day.
+
+use aoc::days::{eight, five, four, nine, one, seven, six, three, two};
+use aoc::generate::generate;
+use aoc_common::{Part, Solver};
+use criterion::measurement::WallTime;
+use criterion::{BenchmarkGroup, BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::hint::black_box;
+
+const SEED: u64 = 2025;
+
+fn bench_input<S: Solver>(group: &mut BenchmarkGroup<WallTime>, label: &str, input: &str) {
+    group.bench_with_input(BenchmarkId::new("parse", label), input, |b, input| {
+        b.iter(|| S::parse(black_box(input)).unwrap())
+    });
+    let parsed = S::parse(input).unwrap();
+    for part in [Part::One, Part::Two] {
+        group.bench_with_input(
+            BenchmarkId::new(format!("part-{part}"), label),
+            &parsed,
+            |b, parsed| b.iter(|| S::solve(black_box(parsed), part)),
+        );
+    }
+}
+
+fn day_one(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-1");
+    bench_input::<one::DayOne>(&mut group, "example", one::EXAMPLE.input);
+    let large = generate(1, SEED, 100_000).unwrap();
+    bench_input::<one::DayOne>(&mut group, "large", &large);
+    group.finish();
+}
+
+fn day_two(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-2");
+    bench_input::<two::DayTwo>(&mut group, "example", two::EXAMPLE.input);
+    let large = generate(2, SEED, 200).unwrap();
+    bench_input::<two::DayTwo>(&mut group, "large", &large);
+    group.finish();
+}
+
+fn day_three(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-3");
+    bench_input::<three::DayThree<15>>(&mut group, "example", three::EXAMPLE.input);
+    let large = generate(3, SEED, 10_000).unwrap();
+    bench_input::<three::DayThree>(&mut group, "large", &large);
+    group.finish();
+}
+
+fn day_four(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-4");
+    bench_input::<four::DayFour>(&mut group, "example", four::EXAMPLE.input);
+    let large = generate(4, SEED, 500).unwrap();
+    bench_input::<four::DayFour>(&mut group, "large", &large);
+    group.finish();
+}
+
+fn day_five(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-5");
+    bench_input::<five::DayFive>(&mut group, "example", five::EXAMPLE.input);
+    let large = generate(5, SEED, 5_000).unwrap();
+    bench_input::<five::DayFive>(&mut group, "large", &large);
+    group.finish();
+}
+
+fn day_six(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-6");
+    bench_input::<six::DaySix>(&mut group, "example", six::EXAMPLE.input);
+    let large = generate(6, SEED, 10_000).unwrap();
+    bench_input::<six::DaySix>(&mut group, "large", &large);
+    group.finish();
+}
+
+fn day_seven(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-7");
+    bench_input::<seven::DaySeven>(&mut group, "example", seven::EXAMPLE.input);
+    let large = generate(7, SEED, 300).unwrap();
+    bench_input::<seven::DaySeven>(&mut group, "large", &large);
+    group.finish();
+}
+
+// Edge generation is quadratic in the number of junction boxes.
+fn day_eight(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-8");
+    group.sample_size(10);
+    bench_input::<eight::DayEight<10>>(&mut group, "example", eight::EXAMPLE.input);
+    let large = generate(8, SEED, 2_000).unwrap();
+    bench_input::<eight::DayEight>(&mut group, "large", &large);
+    group.finish();
+}
+
+// Part two's rectangle search is cubic in the number of red tiles.
+fn day_nine(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day-9");
+    group.sample_size(10);
+    bench_input::<nine::DayNine>(&mut group, "example", nine::EXAMPLE.input);
+    let large = generate(9, SEED, 150).unwrap();
+    bench_input::<nine::DayNine>(&mut group, "large", &large);
+    group.finish();
+}
+
+criterion_group!(
+    benches, day_one, day_two, day_three, day_four, day_five, day_six, day_seven, day_eight,
+    day_nine
+);
+criterion_main!(benches);
@@ -0,0 +1,135 @@
+pub mod eight;
+pub mod five;
+pub mod four;
+pub mod nine;
+pub mod one;
+pub mod seven;
+pub mod six;
+pub mod three;
+pub mod two;
+
+use aoc_common::{Answer, Extras, Part, Solver};
+use eight::DayEight;
+use five::DayFive;
+use four::DayFour;
+use nine::DayNine;
+use one::DayOne;
+use seven::DaySeven;
+use six::DaySix;
+use std::{error::Error, ops::RangeInclusive, time::Instant};
+use three::DayThree;
+use two::DayTwo;
+
+pub const DAYS: RangeInclusive<u8> = 1..=9;
+pub const PARTS: [Part; 2] = [Part::One, Part::Two];
+
+/// One part's answer, with how long its solve stage took.
+pub struct PartRun {
+    pub part: Part,
+    pub answer: Answer,
+    pub extras: Extras,
+    pub solve_ns: u128,
+}
+
+/// A day's answers for one input, with how long the shared parse stage took.
+pub struct DayRun {
+    pub parse_ns: u128,
+    pub parts: Vec<PartRun>,
+}
+
+fn run_parts<S: Solver>(input: &str, parts: &[Part]) -> Result<DayRun, Box<dyn Error>> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_ns = start.elapsed().as_nanos();
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let start = Instant::now();
+            let (answer, extras) = S::solve_with_extras(&parsed, part)?;
+            Ok(PartRun {
+                part,
+                answer,
+                extras,
+                solve_ns: start.elapsed().as_nanos(),
+            })
+        })
+        .collect::<Result<_, Box<dyn Error>>>()?;
+    Ok(DayRun { parse_ns, parts })
+}
+
+/// Parses `input` once for `day` and solves each of `parts` against it.
+pub fn run(day: u8, input: &str, parts: &[Part]) -> Result<DayRun, Box<dyn Error>> {
+    match day {
+        1 => run_parts::<DayOne>(input, parts),
+        2 => run_parts::<DayTwo>(input, parts),
+        3 => run_parts::<DayThree>(input, parts),
+        4 => run_parts::<DayFour>(input, parts),
+        5 => run_parts::<DayFive>(input, parts),
+        6 => run_parts::<DaySix>(input, parts),
+        7 => run_parts::<DaySeven>(input, parts),
+        8 => run_parts::<DayEight>(input, parts),
+        9 => run_parts::<DayNine>(input, parts),
+        _ => Err(format!("no solver for day {day}").into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Example;
+
+    type Runner = fn(&str, &[Part]) -> Result<DayRun, Box<dyn Error>>;
+
+    // Days three and eight are sized for puzzle inputs, which their examples
+    // are smaller than, so those run at the example's size instead.
+    const EXAMPLES: [(u8, Example, Option<Runner>); 9] = [
+        (1, one::EXAMPLE, None),
+        (2, two::EXAMPLE, None),
+        (3, three::EXAMPLE, Some(run_parts::<DayThree<15>>)),
+        (4, four::EXAMPLE, None),
+        (5, five::EXAMPLE, None),
+        (6, six::EXAMPLE, None),
+        (7, seven::EXAMPLE, None),
+        (8, eight::EXAMPLE, Some(run_parts::<DayEight<10>>)),
+        (9, nine::EXAMPLE, None),
+    ];
+
+    #[test]
+    fn solves_every_example() {
+        assert!(DAYS.eq(EXAMPLES.iter().map(|(day, ..)| *day)));
+        for (day, example, runner) in EXAMPLES {
+            let run = match runner {
+                Some(runner) => runner(example.input, &PARTS),
+                None => run(day, example.input, &PARTS),
+            }
+            .unwrap();
+            let answers: Vec<Answer> = run.parts.into_iter().map(|p| p.answer).collect();
+            assert_eq!(answers, [example.part_one, example.part_two], "day {day}");
+        }
+    }
+
+    #[test]
+    fn rejects_input_that_leaves_nothing_to_solve() {
+        let cases = [
+            (5, "", "fresh ID range"),
+            (
+                5,
+                "3-5\n10-7\n\n4\n",
+                "ends before it starts (raw: \"10-7\")\n --> <input>:2:4",
+            ),
+            (6, "+\n", "operand rows"),
+            (8, "", "two distinct junction boxes"),
+            (8, "1,2,3\n1,2,3\n", "two distinct junction boxes"),
+            (9, "", "two red tiles"),
+            (9, "1,2\n", "two red tiles"),
+            (9, "1,2\n3,4\n", "same row"),
+            (9, "1,2\n3,2\n3,4\n", "same column"),
+        ];
+        for (day, input, message) in cases {
+            let Err(e) = run(day, input, &PARTS) else {
+                panic!("day {day} solved {input:?}");
+            };
+            assert!(e.to_string().contains(message), "day {day}: {e}");
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use aoc_common::{Answer, Example, Extras, ParseError, Part, Solver};
+use aoc8pt1::Vertex;
+use serde_json::json;
+use std::error::Error;
+
+/// Generic over how many of the shortest connections part one makes; puzzle
+/// inputs use 1000.
+pub struct DayEight<const CONNECTIONS: usize = 1000>;
+
+impl<const CONNECTIONS: usize> Solver for DayEight<CONNECTIONS> {
+    type Input = Vec<Vertex>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc8pt1::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc8pt1::calculate_largest_circuits_product::<CONNECTIONS>(input).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc8pt2::calculate_last_edge_x_product(input).into())
+    }
+
+    fn solve_with_extras(
+        input: &Self::Input,
+        part: Part,
+    ) -> Result<(Answer, Extras), Box<dyn Error>> {
+        match part {
+            Part::One => Ok((Self::part_one(input)?, Extras::new())),
+            Part::Two => {
+                let (u, v) = aoc8pt2::calculate_last_edge(input);
+                let mut extras = Extras::new();
+                extras.insert(
+                    "last_edge".into(),
+                    json!([[u.x, u.y, u.z], [v.x, v.y, v.z]]),
+                );
+                Ok(((u.x * v.x).into(), extras))
+            }
+        }
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: "\
+162,817,812
+57,618,57
+906,360,560
+592,479,940
+352,342,300
+466,668,158
+542,29,236
+431,825,988
+739,650,466
+52,470,668
+216,146,977
+819,987,18
+117,168,530
+805,96,715
+346,949,466
+970,615,88
+941,993,340
+862,61,35
+984,92,344
+425,690,689
+",
+    part_one: Answer::Unsigned(40),
+    part_two: Answer::Unsigned(25272),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_last_edge() {
+        let input = DayEight::<10>::parse(EXAMPLE.input).unwrap();
+        let (answer, extras) = DayEight::<10>::solve_with_extras(&input, Part::Two).unwrap();
+        assert_eq!(answer, EXAMPLE.part_two);
+        assert_eq!(
+            extras["last_edge"],
+            json!([[216, 146, 977], [117, 168, 530]])
+        );
+    }
+}
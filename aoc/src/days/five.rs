@@ -0,0 +1,39 @@
+use aoc_common::{Answer, Example, ParseError, Solver};
+use aoc5pt1::{Ids, Ranges};
+use std::error::Error;
+
+pub struct DayFive;
+
+impl Solver for DayFive {
+    type Input = (Ranges, Ids);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc5pt1::parse(input)
+    }
+
+    fn part_one((ranges, ids): &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc5pt1::calculate_total_fresh_ingredients(ranges, ids).into())
+    }
+
+    fn part_two((ranges, _): &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc5pt2::calculate_total_fresh_ingredients(ranges).into())
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: "\
+3-5
+10-14
+16-20
+12-18
+
+1
+5
+8
+11
+17
+32
+",
+    part_one: Answer::Unsigned(3),
+    part_two: Answer::Unsigned(14),
+};
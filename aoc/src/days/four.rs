@@ -0,0 +1,37 @@
+use aoc_common::{Answer, Example, ParseError, Solver};
+use std::error::Error;
+
+pub struct DayFour;
+
+impl Solver for DayFour {
+    type Input = Vec<Vec<bool>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc4pt1::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc4pt1::calculate_total_rolls(input).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc4pt2::calculate_total_rolls(input).into())
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: "\
+..@@.@@@@.
+@@@.@.@.@@
+@@@@@.@.@@
+@.@@@@..@.
+@@.@@@@.@@
+.@@@@@@@.@
+.@.@.@.@@@
+@.@@@.@@@@
+.@@@@@@@@.
+@.@.@@@.@.
+",
+    part_one: Answer::Unsigned(13),
+    part_two: Answer::Unsigned(43),
+};
@@ -0,0 +1,69 @@
+use aoc_common::{Answer, Example, Extras, ParseError, Part, Solver};
+use aoc9pt1::Vertex;
+use serde_json::json;
+use std::error::Error;
+
+pub struct DayNine;
+
+impl Solver for DayNine {
+    type Input = Vec<Vertex>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc9pt1::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc9pt1::get_largest_rectangle_area(input).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc9pt2::get_largest_rectangle_area(&aoc9pt2::get_all_edges(input)).into())
+    }
+
+    fn solve_with_extras(
+        input: &Self::Input,
+        part: Part,
+    ) -> Result<(Answer, Extras), Box<dyn Error>> {
+        let largest = match part {
+            Part::One => aoc9pt1::get_largest_rectangle(input),
+            Part::Two => aoc9pt2::get_largest_rectangle(&aoc9pt2::get_all_edges(input)),
+        };
+        let mut extras = Extras::new();
+        let area = match largest {
+            Some(((a, b), area)) => {
+                extras.insert("corners".into(), json!([[a.0, a.1], [b.0, b.1]]));
+                area
+            }
+            None => 0,
+        };
+        Ok((area.into(), extras))
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: "\
+7,1
+11,1
+11,7
+9,7
+9,5
+2,5
+2,3
+7,3
+",
+    part_one: Answer::Unsigned(50),
+    part_two: Answer::Unsigned(24),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_winning_corners() {
+        let input = DayNine::parse(EXAMPLE.input).unwrap();
+        let (answer, extras) = DayNine::solve_with_extras(&input, Part::Two).unwrap();
+        assert_eq!(answer, EXAMPLE.part_two);
+        assert_eq!(extras["corners"], json!([[2, 3], [9, 5]]));
+    }
+}
@@ -0,0 +1,103 @@
+use aoc_common::{Answer, Example, Extras, ParseError, Part, Solver};
+use safe_combo::{
+    DIAL_POSITIONS, PasswordCounter, Program, ZeroPolicy, calculate_program_password,
+};
+use std::error::Error;
+
+pub struct DayOne;
+
+impl Solver for DayOne {
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Program::parse(input, DIAL_POSITIONS)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(calculate_program_password(input, ZeroPolicy::LandsOn)?
+            .password
+            .into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(
+            calculate_program_password(input, ZeroPolicy::PassesThrough)?
+                .password
+                .into(),
+        )
+    }
+
+    fn solve_with_extras(
+        input: &Self::Input,
+        part: Part,
+    ) -> Result<(Answer, Extras), Box<dyn Error>> {
+        let policy = match part {
+            Part::One => ZeroPolicy::LandsOn,
+            Part::Two => ZeroPolicy::PassesThrough,
+        };
+        let PasswordCounter { total, password } = calculate_program_password(input, policy)?;
+        let mut extras = Extras::new();
+        extras.insert("final_position".into(), total.into());
+        Ok((password.into(), extras))
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: "\
+L68
+L30
+R48
+L5
+R60
+L55
+L1
+L99
+R14
+L82
+",
+    part_one: Answer::Unsigned(3),
+    part_two: Answer::Unsigned(6),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_final_position() {
+        let input = DayOne::parse(EXAMPLE.input).unwrap();
+        for part in [Part::One, Part::Two] {
+            let (_, extras) = DayOne::solve_with_extras(&input, part).unwrap();
+            assert_eq!(extras["final_position"], 32);
+        }
+    }
+
+    #[test]
+    fn counts_past_i16() {
+        let input = DayOne::parse(&"R100000\n".repeat(1000)).unwrap();
+        assert_eq!(
+            DayOne::part_two(&input).unwrap(),
+            Answer::Unsigned(1_000_000)
+        );
+    }
+
+    #[test]
+    fn unrolls_repeats_as_it_goes() {
+        let input = DayOne::parse("1000000000x(R1)\n").unwrap();
+        assert_eq!(input.combos(0).nth(999), Some((1, "R1".parse().unwrap())));
+
+        let input = DayOne::parse("1000x(R100000)\n").unwrap();
+        assert_eq!(
+            DayOne::part_two(&input).unwrap(),
+            Answer::Unsigned(1_000_000)
+        );
+    }
+
+    #[test]
+    fn reports_overflowing_line() {
+        let turn = format!("R{}\n", u64::MAX);
+        let input = DayOne::parse(&turn.repeat(200)).unwrap();
+        let e = DayOne::part_two(&input).unwrap_err();
+        assert!(e.to_string().ends_with("on line 101"), "{e}");
+    }
+}
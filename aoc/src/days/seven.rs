@@ -0,0 +1,44 @@
+use aoc_common::{Answer, Example, ParseError, Solver};
+use aoc7pt1::ProblemMeta;
+use std::error::Error;
+
+pub struct DaySeven;
+
+impl Solver for DaySeven {
+    type Input = ProblemMeta;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc7pt1::parse(input)
+    }
+
+    fn part_one((beams, splitters, width, height): &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc7pt1::calculate_total_beams(beams, splitters, *width, *height).into())
+    }
+
+    fn part_two((beams, splitters, width, height): &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc7pt2::calculate_total_beams(beams, splitters, *width, *height).into())
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: "\
+.......S.......
+...............
+.......^.......
+...............
+......^.^......
+...............
+.....^.^.^.....
+...............
+....^.^...^....
+...............
+...^.^...^.^...
+...............
+..^...^.....^..
+...............
+.^.^.^.^.^...^.
+...............
+",
+    part_one: Answer::Unsigned(21),
+    part_two: Answer::Unsigned(40),
+};
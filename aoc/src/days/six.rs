@@ -0,0 +1,49 @@
+use aoc_common::{Answer, Example, ParseError, Solver};
+use aoc6pt1::Worksheet;
+use std::error::Error;
+
+pub struct DaySix;
+
+impl Solver for DaySix {
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc6pt1::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc6pt1::calculate_answer_sum(&aoc6pt1::read_problems(input)).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc6pt2::read_problems(input).sum().into())
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: concat!(
+        "123 328  51 64 \n",
+        " 45 64  387 23 \n",
+        "  6 98  215 314\n",
+        "*   +   *   +  \n",
+    ),
+    part_one: Answer::Unsigned(4277556),
+    part_two: Answer::Unsigned(3263827),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solves_rows_without_trailing_spaces() {
+        let trimmed: String = EXAMPLE
+            .input
+            .lines()
+            .map(|l| l.trim_end().to_owned() + "\n")
+            .collect();
+        let input = DaySix::parse(&trimmed).unwrap();
+        assert_eq!(DaySix::part_one(&input).unwrap(), EXAMPLE.part_one);
+        assert_eq!(DaySix::part_two(&input).unwrap(), EXAMPLE.part_two);
+    }
+}
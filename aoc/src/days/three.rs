@@ -0,0 +1,33 @@
+use aoc_common::{Answer, Example, ParseError, Solver};
+use aoc3pt1::joltage::JoltageLayout;
+use std::error::Error;
+
+/// Generic over the number of batteries per bank; puzzle inputs use 100.
+pub struct DayThree<const N: usize = 100>;
+
+impl<const N: usize> Solver for DayThree<N> {
+    type Input = Vec<JoltageLayout<N>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        aoc3pt1::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc3pt1::calculate_joltage_sum(input).into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(aoc3pt2::calculate_joltage_sum(input).into())
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: "\
+987654321111111
+811111111111119
+234234234234278
+818181911112111
+",
+    part_one: Answer::Unsigned(357),
+    part_two: Answer::Unsigned(3121910778619),
+};
@@ -0,0 +1,56 @@
+use aoc_common::{Answer, Example, ParseError, Solver};
+use aoc2pt1::{Products, products::RepetitionRule};
+use std::error::Error;
+
+pub struct DayTwo;
+
+impl Solver for DayTwo {
+    type Input = Products;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Products::parse(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(input.sum_invalid_ids(&RepetitionRule::twice())?.into())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Answer, Box<dyn Error>> {
+        Ok(input
+            .sum_invalid_ids(&RepetitionRule::at_least_twice())?
+            .into())
+    }
+}
+
+pub const EXAMPLE: Example = Example {
+    input: "\
+11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124
+",
+    part_one: Answer::Unsigned(1227775554),
+    part_two: Answer::Unsigned(4174379265),
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_past_u64() {
+        let id: u128 = 123_456_789_012_345_123_456_789_012_345;
+        let input = DayTwo::parse(&format!("{}-{}", id - 5, id + 5)).unwrap();
+        assert_eq!(DayTwo::part_one(&input).unwrap(), id.into());
+        assert_eq!(DayTwo::part_two(&input).unwrap(), id.into());
+
+        // Every half up to `1844674407` doubled, which overflows `u64`.
+        let input = DayTwo::parse(&format!("1-{}", u64::MAX)).unwrap();
+        let sum: u128 = (1..=10)
+            .map(|k| {
+                let (first, last) = (10u128.pow(k - 1), (10u128.pow(k) - 1).min(1_844_674_407));
+                (10u128.pow(k) + 1) * (first + last) * (last - first + 1) / 2
+            })
+            .sum();
+        let answer = DayTwo::part_one(&input).unwrap();
+        assert!(matches!(answer, Answer::Big(_)));
+        assert_eq!(answer, sum.into());
+    }
+}
@@ -0,0 +1,261 @@
+//! Deterministic puzzle inputs in every day's format, for stress tests and
+//! benchmarks that can't use personal puzzle inputs.
+
+use std::{error::Error, fmt::Write};
+
+/// Roughly the size of a real puzzle input for `day`, in the units `size`
+/// takes in [`generate`].
+pub fn default_size(day: u8) -> Option<usize> {
+    Some(match day {
+        1 => 4_500,
+        2 => 35,
+        3 => 200,
+        4 => 140,
+        5 => 1_000,
+        6 => 1_000,
+        7 => 142,
+        8 => 1_000,
+        9 => 250,
+        _ => return None,
+    })
+}
+
+/// An input for `day` with `size` lines, ranges, rows or tiles, depending on
+/// the day's format; the same seed always yields the same input.
+pub fn generate(day: u8, seed: u64, size: usize) -> Result<String, Box<dyn Error>> {
+    // Day eight needs two junction boxes to connect.
+    let min_size = if day == 8 { 2 } else { 1 };
+    if size < min_size {
+        return Err(format!("size must be at least {min_size} for day {day}").into());
+    }
+    let rng = &mut Rng::new(seed);
+    Ok(match day {
+        1 => day_one(rng, size),
+        2 => day_two(rng, size),
+        3 => day_three(rng, size),
+        4 => day_four(rng, size),
+        5 => day_five(rng, size),
+        6 => day_six(rng, size),
+        7 => day_seven(rng, size | 1, size),
+        8 => day_eight(rng, size),
+        9 => day_nine(rng, size),
+        _ => return Err(format!("no generator for day {day}").into()),
+    })
+}
+
+/// SplitMix64; small and stable, so the same seed always yields the same
+/// input.
+struct Rng(u64);
+
+impl Rng {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `low..high`.
+    fn range(&mut self, low: u64, high: u64) -> u64 {
+        low + self.next_u64() % (high - low)
+    }
+
+    fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.range(0, denominator) < numerator
+    }
+}
+
+/// `lines` rotations of up to 999 clicks.
+fn day_one(rng: &mut Rng, lines: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..lines {
+        let direction = if rng.chance(1, 2) { 'L' } else { 'R' };
+        let _ = writeln!(input, "{direction}{}", rng.range(1, 1000));
+    }
+    input
+}
+
+/// `ranges` ID ranges of up to 10 digits, each spanning fewer than 100k IDs.
+fn day_two(rng: &mut Rng, ranges: usize) -> String {
+    let ranges: Vec<String> = (0..ranges)
+        .map(|_| {
+            let lower = rng.range(1, 10_000_000_000);
+            format!("{lower}-{}", lower + rng.range(0, 100_000))
+        })
+        .collect();
+    ranges.join(",") + "\n"
+}
+
+/// `banks` banks of 100 batteries.
+fn day_three(rng: &mut Rng, banks: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..banks {
+        input.extend((0..100).map(|_| char::from(b'0' + rng.range(1, 10) as u8)));
+        input.push('\n');
+    }
+    input
+}
+
+/// A `side` by `side` grid, about half of it rolls.
+fn day_four(rng: &mut Rng, side: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..side {
+        input.extend((0..side).map(|_| if rng.chance(1, 2) { '@' } else { '.' }));
+        input.push('\n');
+    }
+    input
+}
+
+/// `count` overlapping fresh ranges followed by `count` ingredient IDs,
+/// with range widths scaled to `count` so about half of the IDs are fresh.
+fn day_five(rng: &mut Rng, count: usize) -> String {
+    const IDS: u64 = 1_000_000_000_000;
+    let mut input = String::new();
+    for _ in 0..count {
+        let lower = rng.range(1, IDS);
+        let _ = writeln!(
+            input,
+            "{lower}-{}",
+            lower + rng.range(0, 3 * IDS / 2 / count as u64 + 1)
+        );
+    }
+    input.push('\n');
+    for _ in 0..count {
+        let _ = writeln!(input, "{}", rng.range(1, IDS));
+    }
+    input
+}
+
+/// `problems` problems of four operands each. A problem's numbers share one
+/// alignment and are ordered by width, so every column inside a problem holds
+/// one contiguous run of digits.
+fn day_six(rng: &mut Rng, problems: usize) -> String {
+    const OPERANDS: usize = 4;
+    let mut rows = vec![String::new(); OPERANDS + 1];
+    for p in 0..problems {
+        let mut numbers: Vec<String> = (0..OPERANDS)
+            .map(|_| {
+                let digits = rng.range(1, 5) as u32;
+                rng.range(10u64.pow(digits - 1), 10u64.pow(digits))
+                    .to_string()
+            })
+            .collect();
+        numbers.sort_by_key(String::len);
+        if rng.chance(1, 2) {
+            numbers.reverse();
+        }
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let right_aligned = rng.chance(1, 2);
+        let separator = if p + 1 < problems { " " } else { "" };
+        for (row, number) in rows.iter_mut().zip(&numbers) {
+            if right_aligned {
+                let _ = write!(row, "{number:>width$}{separator}");
+            } else {
+                let _ = write!(row, "{number:<width$}{separator}");
+            }
+        }
+        let operator = if rng.chance(1, 2) { '+' } else { '*' };
+        let _ = write!(rows[OPERANDS], "{operator:<width$}{separator}");
+    }
+    rows.join("\n") + "\n"
+}
+
+/// A `width` by `height` manifold with splitters on every other row.
+fn day_seven(rng: &mut Rng, width: usize, height: usize) -> String {
+    let mut input = String::new();
+    for y in 0..height {
+        input.extend((0..width).map(|x| match (x, y) {
+            (x, 0) if x == width / 2 => 'S',
+            (x, y) if y % 2 == 0 && y > 0 && x > 0 && x + 1 < width && rng.chance(1, 4) => '^',
+            _ => '.',
+        }));
+        input.push('\n');
+    }
+    input
+}
+
+/// `boxes` junction boxes within a 100k cube.
+fn day_eight(rng: &mut Rng, boxes: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..boxes {
+        let [x, y, z] = [(); 3].map(|_| rng.range(0, 100_000));
+        let _ = writeln!(input, "{x},{y},{z}");
+    }
+    input
+}
+
+/// A rectilinear polygon of `2 * columns + 2` red tiles: a flat bottom
+/// edge under a skyline of `columns` distinct heights. Distinct heights keep
+/// every row and column holding exactly two tiles, and starting at the
+/// bottom-right corner makes the first edge shorter than the closing one;
+/// part two relies on both to recover the tile order.
+fn day_nine(rng: &mut Rng, columns: usize) -> String {
+    let mut heights: Vec<u64> = (1..=columns as u64).map(|h| h * 7).collect();
+    for i in (1..heights.len()).rev() {
+        heights.swap(i, rng.range(0, i as u64 + 1) as usize);
+    }
+    let x = |i: usize| i as u64 * 11;
+
+    let mut tiles = vec![(x(columns), 0)];
+    for (i, &height) in heights.iter().enumerate().rev() {
+        tiles.push((x(i + 1), height));
+        tiles.push((x(i), height));
+    }
+    tiles.push((0, 0));
+    tiles.iter().fold(String::new(), |mut input, (x, y)| {
+        let _ = writeln!(input, "{x},{y}");
+        input
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::{self, DAYS, PARTS};
+
+    #[test]
+    fn every_day_generates_solvable_input() {
+        for day in DAYS {
+            for size in [2, 25] {
+                let input = generate(day, 7, size).unwrap();
+                if let Err(e) = days::run(day, &input, &PARTS) {
+                    panic!("day {day} size {size}: {e}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn refuses_sizes_too_small_to_solve() {
+        for day in DAYS {
+            for size in [0, 1] {
+                if let Ok(input) = generate(day, 7, size)
+                    && let Err(e) = days::run(day, &input, &PARTS)
+                {
+                    panic!("day {day} size {size}: {e}");
+                }
+            }
+        }
+        assert!(generate(8, 7, 1).is_err());
+    }
+
+    #[test]
+    fn same_seed_same_input() {
+        for day in DAYS {
+            let size = default_size(day).unwrap();
+            assert_eq!(
+                generate(day, 1, size).unwrap(),
+                generate(day, 1, size).unwrap()
+            );
+            assert_ne!(
+                generate(day, 1, size).unwrap(),
+                generate(day, 2, size).unwrap()
+            );
+        }
+    }
+}
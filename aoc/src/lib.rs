@@ -0,0 +1,4 @@
+pub mod days;
+pub mod generate;
+pub mod registry;
+pub mod report;
@@ -0,0 +1,216 @@
+use aoc::{
+    days::{self, DayRun},
+    generate,
+    registry::Registry,
+    report::{Report, input_hash},
+};
+use aoc_common::{InputSource, Part, read_input};
+use clap::{Parser, Subcommand, ValueEnum};
+use std::{
+    error::Error,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day and part against an input file, or every day with --all
+    Run {
+        #[arg(required_unless_present = "all")]
+        day: Option<u8>,
+        #[arg(required_unless_present = "all", value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Input file, or `-` to read stdin
+        #[arg(required_unless_present_any = ["all", "input_str"])]
+        input: Option<String>,
+        /// Puzzle input given inline instead of a file
+        #[arg(long, value_name = "INPUT", conflicts_with = "input")]
+        input_str: Option<String>,
+        /// Directory holding one `day-<n>.txt` input per day
+        #[arg(long, value_name = "INPUTS_DIR", conflicts_with_all = ["day", "part", "input", "input_str"])]
+        all: Option<String>,
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+    },
+    /// Print a synthetic input for a day
+    Gen {
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Lines, ranges, rows or tiles depending on the day; defaults to
+        /// about the size of a real input
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Re-run every day and compare against recorded answers
+    Verify {
+        /// Directory holding one `day-<n>.txt` input per day
+        inputs_dir: PathBuf,
+        /// Answer registry keyed by day, part and input SHA-256
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+        /// Record the current answers, replacing any that changed
+        #[arg(long)]
+        record: bool,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    /// `day <n> part <m>: <answer>` per line
+    Text,
+    /// One JSON object per line with timings, input hash and extras
+    Json,
+}
+
+fn load_and_run(
+    day: u8,
+    source: &InputSource,
+    parts: &[Part],
+) -> Result<(String, DayRun), Box<dyn Error>> {
+    let input = source.read(|reader| Ok(read_input(reader)?))?;
+    let run = days::run(day, &input, parts).map_err(|e| source.label(e))?;
+    Ok((input, run))
+}
+
+fn run_source(
+    day: u8,
+    source: &InputSource,
+    parts: &[Part],
+    format: Format,
+) -> Result<(), Box<dyn Error>> {
+    let (input, run) = load_and_run(day, source, parts)?;
+    match format {
+        Format::Text => {
+            for part_run in run.parts {
+                println!("day {day} part {}: {}", part_run.part, part_run.answer);
+            }
+        }
+        Format::Json => {
+            for report in Report::from_run(day, &input, run) {
+                println!("{}", serde_json::to_string(&report)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_all(inputs_dir: &str, format: Format) -> ExitCode {
+    let mut failed = false;
+    for day in days::DAYS {
+        let source = InputSource::Path(Path::new(inputs_dir).join(format!("day-{day}.txt")));
+        if let Err(e) = run_source(day, &source, &days::PARTS, format) {
+            failed = true;
+            eprintln!("day {day}: error: {e}");
+        }
+    }
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(inputs_dir: &Path, answers: &Path, record: bool) -> Result<ExitCode, Box<dyn Error>> {
+    let mut registry = Registry::load(answers)?;
+    let mut failed = false;
+    for day in days::DAYS {
+        let path = inputs_dir.join(format!("day-{day}.txt"));
+        if !path.exists() {
+            println!("day {day}: skipped, no {}", path.display());
+            continue;
+        }
+        let (input, run) = match load_and_run(day, &InputSource::Path(path), &days::PARTS) {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                failed = true;
+                eprintln!("day {day}: error: {e}");
+                continue;
+            }
+        };
+        let hash = input_hash(&input);
+        for part_run in run.parts {
+            let part = u8::from(part_run.part);
+            let answer = part_run.answer.to_string();
+            let status = match registry.get(day, part, &hash) {
+                Some(expected) if expected == answer => "ok".to_owned(),
+                Some(expected) if record => format!("updated, was {expected}"),
+                Some(expected) => {
+                    failed = true;
+                    format!("MISMATCH, expected {expected}")
+                }
+                None if record => "recorded".to_owned(),
+                None => "unrecorded".to_owned(),
+            };
+            println!("day {day} part {part}: {answer} ({status})");
+            if record {
+                registry.record(day, part, &hash, answer);
+            }
+        }
+    }
+    if record {
+        registry.save(answers)?;
+    }
+    Ok(if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<ExitCode, Box<dyn Error>> {
+    match cli.command {
+        Command::Run {
+            all: Some(inputs_dir),
+            format,
+            ..
+        } => Ok(run_all(&inputs_dir, format)),
+        Command::Run {
+            day: Some(day),
+            part: Some(part),
+            input,
+            input_str,
+            format,
+            ..
+        } => {
+            let source = match (input, input_str) {
+                (_, Some(input)) => InputSource::Inline(input),
+                (Some(arg), None) => InputSource::from(arg.as_str()),
+                (None, None) => unreachable!("clap requires an input without --all"),
+            };
+            let part = Part::try_from(part)?;
+            run_source(day, &source, &[part], format)?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Gen { day, seed, size } => {
+            let size = size
+                .or_else(|| generate::default_size(day))
+                .ok_or_else(|| format!("no generator for day {day}"))?;
+            print!("{}", generate::generate(day, seed, size)?);
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Verify {
+            inputs_dir,
+            answers,
+            record,
+        } => verify(&inputs_dir, &answers, record),
+        Command::Run { .. } => unreachable!("clap enforces day and part without --all"),
+    }
+}
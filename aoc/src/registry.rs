@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+use std::{error::Error, fs, io, path::Path};
+
+/// Recorded answers keyed by day, part and the SHA-256 of the input they
+/// were computed from, stored as TOML.
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Registry {
+    #[serde(default, rename = "answer")]
+    entries: Vec<Entry>,
+}
+
+/// Answers are kept as strings since TOML integers stop at `i64`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: u8,
+    pub part: u8,
+    pub input_sha256: String,
+    pub answer: String,
+}
+
+impl Registry {
+    /// A missing file is an empty registry.
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                Ok(toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))?)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e).into()),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), Box<dyn Error>> {
+        fs::write(path, toml::to_string(self)?)
+            .map_err(|e| format!("{}: {}", path.display(), e).into())
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_sha256: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|e| e.day == day && e.part == part && e.input_sha256 == input_sha256)
+            .map(|e| e.answer.as_str())
+    }
+
+    /// Stores `answer`, replacing any earlier one for the same key.
+    pub fn record(&mut self, day: u8, part: u8, input_sha256: &str, answer: String) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|e| e.day == day && e.part == part && e.input_sha256 == input_sha256)
+        {
+            Some(entry) => entry.answer = answer,
+            None => {
+                self.entries.push(Entry {
+                    day,
+                    part,
+                    input_sha256: input_sha256.to_owned(),
+                    answer,
+                });
+                self.entries.sort_by(|a, b| {
+                    (a.day, a.part, &a.input_sha256).cmp(&(b.day, b.part, &b.input_sha256))
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut registry = Registry::default();
+        registry.record(2, 1, "ab", "1227775554".into());
+        registry.record(1, 2, "cd", "6".into());
+        registry.record(2, 1, "ab", "4174379265".into());
+
+        let parsed: Registry = toml::from_str(&toml::to_string(&registry).unwrap()).unwrap();
+        assert_eq!(parsed, registry);
+        assert_eq!(parsed.get(2, 1, "ab"), Some("4174379265"));
+        assert_eq!(parsed.get(1, 2, "ab"), None);
+        assert_eq!(parsed.entries[0].day, 1);
+    }
+}
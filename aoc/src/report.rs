@@ -0,0 +1,46 @@
+use crate::days::DayRun;
+use aoc_common::Extras;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+/// One line of `--format json` output.
+#[derive(Serialize)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub answer: serde_json::Value,
+    pub parse_ns: u128,
+    pub solve_ns: u128,
+    pub input_hash: String,
+    #[serde(flatten)]
+    pub extras: Extras,
+}
+
+impl Report {
+    pub fn from_run(day: u8, input: &str, run: DayRun) -> Vec<Report> {
+        let input_hash = input_hash(input);
+        run.parts
+            .into_iter()
+            .map(|part_run| Report {
+                day,
+                part: part_run.part.into(),
+                answer: part_run.answer.into(),
+                parse_ns: run.parse_ns,
+                solve_ns: part_run.solve_ns,
+                input_hash: input_hash.clone(),
+                extras: part_run.extras,
+            })
+            .collect()
+    }
+}
+
+/// Hex-encoded SHA-256 of the puzzle input.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, byte| {
+            let _ = write!(hex, "{byte:02x}");
+            hex
+        })
+}
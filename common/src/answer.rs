@@ -0,0 +1,83 @@
+use num_bigint::{BigInt, BigUint};
+use std::fmt;
+
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Signed(i64),
+    Unsigned(u64),
+    /// Past `u64`, which [`From<BigUint>`](Answer::from) leaves to
+    /// [`Unsigned`](Answer::Unsigned) otherwise.
+    Big(BigUint),
+}
+
+impl Answer {
+    fn value(&self) -> BigInt {
+        match self {
+            Answer::Signed(n) => (*n).into(),
+            Answer::Unsigned(n) => (*n).into(),
+            Answer::Big(n) => n.clone().into(),
+        }
+    }
+}
+
+// Answers compare by value so that a solver returning `u32` matches an
+// expected answer written as `i64`.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+
+impl Eq for Answer {}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Signed(n) => write!(f, "{n}"),
+            Answer::Unsigned(n) => write!(f, "{n}"),
+            Answer::Big(n) => write!(f, "{n}"),
+        }
+    }
+}
+
+// JSON numbers past `u64` lose precision in most readers, so those are
+// written as strings.
+impl From<Answer> for serde_json::Value {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Signed(n) => n.into(),
+            Answer::Unsigned(n) => n.into(),
+            Answer::Big(n) => n.to_string().into(),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident => $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as _)
+                }
+            }
+        )+
+    };
+}
+
+impl_from!(Signed => i16, i32, i64);
+impl_from!(Unsigned => u32, u64, usize);
+
+impl From<u128> for Answer {
+    fn from(n: u128) -> Self {
+        BigUint::from(n).into()
+    }
+}
+
+impl From<BigUint> for Answer {
+    fn from(n: BigUint) -> Self {
+        match u64::try_from(&n) {
+            Ok(n) => Answer::Unsigned(n),
+            Err(_) => Answer::Big(n),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::{error::Error, fmt, ops::Range};
+
+/// A parse failure pinned to a line and column span of the input, rendered
+/// like a compiler diagnostic:
+///
+/// ```text
+/// invalid rotation steps (raw: "1x")
+///  --> input.txt:3:2
+///   |
+/// 3 | L1x
+///   |  ^^
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub message: String,
+    pub path: Option<String>,
+    /// One-based line number.
+    pub line: usize,
+    /// Byte offsets into `snippet`.
+    pub columns: Range<usize>,
+    pub snippet: String,
+}
+
+impl ParseError {
+    /// `line` is the zero-based index of the offending line and `text` its
+    /// contents.
+    pub fn new(message: impl fmt::Display, line: usize, text: &str, columns: Range<usize>) -> Self {
+        let end = columns.end.min(text.len());
+        Self {
+            message: message.to_string(),
+            path: None,
+            line: line + 1,
+            columns: columns.start.min(end)..end,
+            snippet: text.to_owned(),
+        }
+    }
+
+    pub fn whole_line(message: impl fmt::Display, line: usize, text: &str) -> Self {
+        Self::new(message, line, text, 0..text.len())
+    }
+
+    /// Points at `part`, which should be a slice of `text`; falls back to its
+    /// first occurrence, then to the whole line.
+    pub fn at(message: impl fmt::Display, line: usize, text: &str, part: &str) -> Self {
+        Self::new(message, line, text, span_of(text, part))
+    }
+
+    pub fn with_path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+pub fn span_of(text: &str, part: &str) -> Range<usize> {
+    let start = (part.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+    if start <= text.len() && start + part.len() <= text.len() {
+        return start..start + part.len();
+    }
+    match text.find(part) {
+        Some(start) => start..start + part.len(),
+        None => 0..text.len(),
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let line_no = self.line.to_string();
+        let gutter = " ".repeat(line_no.len());
+        let before = &self.snippet[..self.columns.start];
+        let column = before.chars().count() + 1;
+        let indent: String = before
+            .chars()
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(self.snippet[self.columns.clone()].chars().count().max(1));
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(
+            f,
+            "{gutter}--> {}:{}:{column}",
+            self.path.as_deref().unwrap_or("<input>"),
+            self.line
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line_no} | {}", self.snippet)?;
+        write!(f, "{gutter} | {indent}{carets}")
+    }
+}
+
+// Errors returned from `main` are printed with `Debug`, so render the
+// diagnostic there too.
+impl fmt::Debug for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_fields;
+
+    #[test]
+    fn renders_caret_under_span() {
+        let text = "L1x";
+        let e =
+            ParseError::at("invalid rotation steps", 2, text, &text[1..]).with_path("input.txt");
+        assert_eq!(
+            e.to_string(),
+            "invalid rotation steps\n --> input.txt:3:2\n  |\n3 | L1x\n  |  ^^"
+        );
+    }
+
+    #[test]
+    fn points_at_bad_field() {
+        let e = parse_fields::<u64, 2>(0, "5-x", '-').unwrap_err();
+        assert_eq!((e.line, e.columns), (1, 2..3));
+        let e = parse_fields::<u64, 2>(0, "1-2-3", '-').unwrap_err();
+        assert_eq!(e.columns, 3..5);
+    }
+}
@@ -0,0 +1,10 @@
+use crate::Answer;
+
+/// A worked example from a puzzle description, with the answer each part
+/// should produce for it.
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub input: &'static str,
+    pub part_one: Answer,
+    pub part_two: Answer,
+}
@@ -0,0 +1,44 @@
+use crate::{ParseError, span_of};
+use std::{fmt, str::FromStr};
+
+/// Splits `text`, the zero-based `line` of the input, on `separator` into
+/// exactly `N` values, pointing at the offending field when one is missing,
+/// surplus or fails to parse.
+pub fn parse_fields<T, const N: usize>(
+    line: usize,
+    text: &str,
+    separator: char,
+) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let mut fields = text.split(separator);
+    let mut values = Vec::with_capacity(N);
+    for i in 0..N {
+        let field = fields.next().ok_or_else(|| {
+            ParseError::new(
+                format!("Expected {N} fields separated by {separator:?}, found {i}"),
+                line,
+                text,
+                text.len()..text.len(),
+            )
+        })?;
+        let value = field.trim().parse::<T>().map_err(|e| {
+            ParseError::at(format!("Invalid field {field:?}: {e}"), line, text, field)
+        })?;
+        values.push(value);
+    }
+    if let Some(extra) = fields.next() {
+        let start = span_of(text, extra).start - separator.len_utf8();
+        return Err(ParseError::new(
+            format!("Expected {N} fields separated by {separator:?}, found more"),
+            line,
+            text,
+            start..text.len(),
+        ));
+    }
+    Ok(values
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("exactly N fields were parsed")))
+}
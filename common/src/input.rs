@@ -0,0 +1,115 @@
+use crate::ParseError;
+use std::{
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::PathBuf,
+};
+
+/// Where puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `-` on the command line.
+    Stdin,
+    Path(PathBuf),
+    /// Passed verbatim with `--input-str`.
+    Inline(String),
+}
+
+impl InputSource {
+    /// Reads `<input-file>`, `-` or `--input-str <input>` from the front of
+    /// `args`.
+    pub fn from_args(mut args: impl Iterator<Item = String>) -> Option<Self> {
+        match args.next()?.as_str() {
+            "--input-str" => args.next().map(Self::Inline),
+            arg => Some(Self::from(arg)),
+        }
+    }
+
+    pub fn reader(&self) -> io::Result<Box<dyn BufRead + '_>> {
+        Ok(match self {
+            Self::Stdin => Box::new(io::stdin().lock()),
+            Self::Path(path) => {
+                Box::new(BufReader::new(File::open(path).map_err(|e| {
+                    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+                })?))
+            }
+            Self::Inline(input) => Box::new(input.as_bytes()),
+        })
+    }
+
+    /// Hands the opened source to `read`, pointing any parse diagnostic at
+    /// this source.
+    // The named lifetime keeps `read` from being higher-ranked, so generic
+    // functions like `safe_combo::read` can be passed as-is.
+    #[allow(clippy::needless_lifetimes)]
+    pub fn read<'a, T>(
+        &'a self,
+        read: impl FnOnce(Box<dyn BufRead + 'a>) -> Result<T, Box<dyn Error>>,
+    ) -> Result<T, Box<dyn Error>> {
+        read(self.reader()?).map_err(|e| self.label(e))
+    }
+
+    pub fn label(&self, e: Box<dyn Error>) -> Box<dyn Error> {
+        match e.downcast::<ParseError>() {
+            Ok(e) => Box::new(e.with_path(self.to_string())),
+            Err(e) => e,
+        }
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(arg: &str) -> Self {
+        match arg {
+            "-" => Self::Stdin,
+            path => Self::Path(path.into()),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdin => write!(f, "<stdin>"),
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Inline(_) => write!(f, "<input-str>"),
+        }
+    }
+}
+
+pub fn read_input(mut reader: impl BufRead) -> io::Result<String> {
+    let mut input = String::new();
+    reader.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Option<InputSource> {
+        InputSource::from_args(args.iter().map(|a| a.to_string()))
+    }
+
+    #[test]
+    fn parses_each_source_form() {
+        assert_eq!(args(&["-"]), Some(InputSource::Stdin));
+        assert_eq!(args(&["in.txt"]), Some(InputSource::Path("in.txt".into())));
+        assert_eq!(
+            args(&["--input-str", "3-5"]),
+            Some(InputSource::Inline("3-5".into()))
+        );
+        assert_eq!(args(&["--input-str"]), None);
+        assert_eq!(args(&[]), None);
+    }
+
+    #[test]
+    fn labels_parse_errors_with_source() {
+        let source = InputSource::Inline("x".into());
+        let e = source
+            .read::<()>(|_| Err(ParseError::whole_line("bad", 0, "x").into()))
+            .unwrap_err();
+        assert!(e.to_string().contains("--> <input-str>:1:1"));
+    }
+}
@@ -0,0 +1,13 @@
+mod answer;
+mod error;
+mod example;
+mod fields;
+mod input;
+mod solver;
+
+pub use answer::Answer;
+pub use error::{ParseError, span_of};
+pub use example::Example;
+pub use fields::parse_fields;
+pub use input::{InputSource, read_input};
+pub use solver::{Extras, Part, Solver};
@@ -0,0 +1,69 @@
+use crate::{Answer, ParseError};
+use std::{error::Error, fmt};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl TryFrom<u8> for Part {
+    type Error = String;
+
+    fn try_from(n: u8) -> Result<Self, Self::Error> {
+        match n {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            _ => Err(format!("invalid part: {n}")),
+        }
+    }
+}
+
+impl From<Part> for u8 {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Auxiliary values a solution produces along the way, keyed by name.
+pub type Extras = serde_json::Map<String, serde_json::Value>;
+
+/// A day's puzzle, split into a parse stage shared by both parts and one
+/// solve stage per part. Solve stages fail only when an answer can't be
+/// represented, such as a counter overflowing.
+pub trait Solver {
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Result<Answer, Box<dyn Error>>;
+
+    fn part_two(input: &Self::Input) -> Result<Answer, Box<dyn Error>>;
+
+    fn solve(input: &Self::Input, part: Part) -> Result<Answer, Box<dyn Error>> {
+        match part {
+            Part::One => Self::part_one(input),
+            Part::Two => Self::part_two(input),
+        }
+    }
+
+    /// Like `solve`, alongside any auxiliary values worth reporting.
+    fn solve_with_extras(
+        input: &Self::Input,
+        part: Part,
+    ) -> Result<(Answer, Extras), Box<dyn Error>> {
+        Ok((Self::solve(input, part)?, Extras::new()))
+    }
+}
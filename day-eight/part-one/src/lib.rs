@@ -0,0 +1,140 @@
+use aoc_common::{ParseError, parse_fields, read_input};
+use std::{collections::HashMap, collections::HashSet, error::Error, io::BufRead};
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct Vertex {
+    pub x: usize,
+    pub y: usize,
+    pub z: usize,
+}
+
+pub type Edge = (Vertex, Vertex);
+
+impl Vertex {
+    pub fn distance_from(self, p: Vertex) -> f64 {
+        let dx = self.x as f64 - p.x as f64;
+        let dy = self.y as f64 - p.y as f64;
+        let dz = self.z as f64 - p.z as f64;
+        dx * dx + dy * dy + dz * dz
+    }
+}
+
+// kruskal's
+// https://www.geeksforgeeks.org/dsa/kruskals-minimum-spanning-tree-algorithm-greedy-algo-2/
+// https://en.wikipedia.org/wiki/Kruskal's_algorithm
+fn find(parent: &mut [usize], i: usize) -> usize {
+    if parent[i] != i {
+        let root = find(parent, parent[i]);
+        parent[i] = root;
+    }
+    parent[i]
+}
+
+fn union(parent: &mut [usize], rank: &mut [usize], a: usize, b: usize) -> bool {
+    let mut ra = find(parent, a);
+    let mut rb = find(parent, b);
+    if ra == rb {
+        return false;
+    }
+
+    if rank[ra] < rank[rb] {
+        std::mem::swap(&mut ra, &mut rb);
+    }
+
+    parent[rb] = ra;
+    if rank[ra] == rank[rb] {
+        rank[ra] += 1;
+    }
+    true
+}
+
+fn n_shortest_edges<const N: usize>(points: &[Vertex]) -> Vec<Edge> {
+    let mut items: Vec<_> = Vec::new();
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let distance = points[i].distance_from(points[j]);
+            items.push(((points[i], points[j]), distance));
+        }
+    }
+    items.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    items.truncate(N.min(items.len()));
+    items.iter().map(|(j, _)| *j).collect()
+}
+
+fn create_mst_forest(edges: &[Edge]) -> Vec<Vec<Edge>> {
+    let mut id: HashMap<Vertex, usize> = HashMap::new();
+    let mut next = 0usize;
+
+    for &(u, v) in edges {
+        for vert in [u, v] {
+            id.entry(vert).or_insert_with(|| {
+                let idx = next;
+                next += 1;
+                idx
+            });
+        }
+    }
+
+    let mut parent: Vec<usize> = (0..next).collect();
+    let mut rank = vec![0; next];
+
+    let mut chosen: Vec<Edge> = Vec::new();
+
+    for &(u, v) in edges {
+        if union(&mut parent, &mut rank, id[&u], id[&v]) {
+            chosen.push((u, v));
+        }
+    }
+
+    let mut forest: HashMap<usize, Vec<Edge>> = HashMap::new();
+    for (u, v) in chosen {
+        let root = find(&mut parent, id[&u]);
+        forest.entry(root).or_default().push((u, v));
+    }
+
+    forest.into_values().collect()
+}
+
+fn count_vertices_in_tree(tree: &[Edge]) -> usize {
+    let mut vertices: HashSet<Vertex> = HashSet::new();
+    for &(u, v) in tree {
+        vertices.insert(u);
+        vertices.insert(v);
+    }
+    vertices.len()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vertex>, ParseError> {
+    let vertices: Vec<Vertex> = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let [x, y, z] = parse_fields(i, line, ',')?;
+            Ok(Vertex { x, y, z })
+        })
+        .collect::<Result<_, ParseError>>()?;
+    // Connecting everything needs at least one edge between distinct boxes.
+    if vertices.iter().all(|v| *v == vertices[0]) {
+        let first = input.lines().next().unwrap_or_default();
+        return Err(ParseError::whole_line(
+            "Expected at least two distinct junction boxes",
+            0,
+            first,
+        ));
+    }
+    Ok(vertices)
+}
+
+pub fn read(reader: impl BufRead) -> Result<Vec<Vertex>, Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
+
+pub fn calculate_largest_circuits_product<const N: usize>(vertices: &[Vertex]) -> usize {
+    let edges = n_shortest_edges::<N>(vertices);
+    let mut forest = create_mst_forest(&edges);
+    forest.sort_by_key(|tree| std::cmp::Reverse(count_vertices_in_tree(tree)));
+    forest.truncate(3);
+    forest
+        .iter()
+        .fold(1, |acc, tree| acc * count_vertices_in_tree(tree))
+}
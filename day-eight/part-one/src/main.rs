@@ -1,144 +1,12 @@
-use std::{
-    collections::HashMap,
-    collections::HashSet,
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct Vertex {
-    pub x: usize,
-    pub y: usize,
-    pub z: usize,
-}
-
-type Edge = (Vertex, Vertex);
-
-impl Vertex {
-    fn distance_from(self, p: Vertex) -> f64 {
-        let dx = self.x as f64 - p.x as f64;
-        let dy = self.y as f64 - p.y as f64;
-        let dz = self.z as f64 - p.z as f64;
-        dx * dx + dy * dy + dz * dz
-    }
-}
-
-// kruskal's
-// https://www.geeksforgeeks.org/dsa/kruskals-minimum-spanning-tree-algorithm-greedy-algo-2/
-// https://en.wikipedia.org/wiki/Kruskal's_algorithm
-fn find(parent: &mut [usize], i: usize) -> usize {
-    if parent[i] != i {
-        let root = find(parent, parent[i]);
-        parent[i] = root;
-    }
-    parent[i]
-}
-
-fn union(parent: &mut [usize], rank: &mut [usize], a: usize, b: usize) -> bool {
-    let mut ra = find(parent, a);
-    let mut rb = find(parent, b);
-    if ra == rb {
-        return false;
-    }
-
-    if rank[ra] < rank[rb] {
-        std::mem::swap(&mut ra, &mut rb);
-    }
-
-    parent[rb] = ra;
-    if rank[ra] == rank[rb] {
-        rank[ra] += 1;
-    }
-    true
-}
-
-fn n_shortest_edges<const N: usize>(points: &[Vertex]) -> Vec<Edge> {
-    let mut items: Vec<_> = Vec::new();
-    for i in 0..points.len() {
-        for j in i + 1..points.len() {
-            let distance = points[i].distance_from(points[j]);
-            let _ = items.push(((points[i], points[j]), distance));
-        }
-    }
-    items.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
-    items.truncate(N.min(items.len()));
-    items.iter().map(|(j, _)| *j).collect()
-}
-
-fn create_mst_forest(edges: &[Edge]) -> Vec<Vec<Edge>> {
-    let mut id: HashMap<Vertex, usize> = HashMap::new();
-    let mut next = 0usize;
-
-    for &(u, v) in edges {
-        for vert in [u, v] {
-            id.entry(vert).or_insert_with(|| {
-                let idx = next;
-                next += 1;
-                idx
-            });
-        }
-    }
-
-    let mut parent: Vec<usize> = (0..next).collect();
-    let mut rank = vec![0; next];
-
-    let mut chosen: Vec<Edge> = Vec::new();
-
-    for &(u, v) in edges {
-        if union(&mut parent, &mut rank, id[&u], id[&v]) {
-            chosen.push((u, v));
-        }
-    }
-
-    let mut forest: HashMap<usize, Vec<Edge>> = HashMap::new();
-    for (u, v) in chosen {
-        let root = find(&mut parent, id[&u]);
-        forest.entry(root).or_default().push((u, v));
-    }
-
-    forest.into_values().collect()
-}
-
-fn count_vertices_in_tree(tree: &[Edge]) -> usize {
-    let mut vertices: HashSet<Vertex> = HashSet::new();
-    for &(u, v) in tree {
-        vertices.insert(u);
-        vertices.insert(v);
-    }
-    vertices.len()
-}
-
-fn read_file(file_path: &str) -> Result<Vec<Vertex>, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<_>, _>>()?;
-
-    let points: Vec<Vertex> = lines
-        .iter()
-        .map(|line| {
-            let mut split = line.split(',');
-            let x = split.next().unwrap().parse().unwrap();
-            let y = split.next().unwrap().parse().unwrap();
-            let z = split.next().unwrap().parse().unwrap();
-            Vertex { x, y, z }
-        })
-        .collect();
-
-    Ok(points)
-}
+use aoc_common::InputSource;
+use aoc8pt1::{Vertex, calculate_largest_circuits_product, read};
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc8pt1 <input-file>");
-    let vertices: Vec<Vertex> = read_file(&path)?;
-    let edges = n_shortest_edges::<1000>(&vertices);
-    let mut forest = create_mst_forest(&edges);
-    forest.sort_by_key(|tree| std::cmp::Reverse(count_vertices_in_tree(tree)));
-    forest.truncate(3);
-    let total = forest
-        .iter()
-        .fold(1, |acc, tree| acc * count_vertices_in_tree(tree));
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc8pt1 <input-file | - | --input-str <input>>");
+    let vertices: Vec<Vertex> = source.read(read)?;
+    let total = calculate_largest_circuits_product::<1000>(&vertices);
     println!("final = {total}");
     Ok(())
 }
@@ -0,0 +1,84 @@
+use aoc8pt1::{Edge, Vertex};
+use std::collections::HashMap;
+
+// kruskal's
+// https://www.geeksforgeeks.org/dsa/kruskals-minimum-spanning-tree-algorithm-greedy-algo-2/
+// https://en.wikipedia.org/wiki/Kruskal's_algorithm
+fn find(parent: &mut [usize], i: usize) -> usize {
+    if parent[i] != i {
+        let root = find(parent, parent[i]);
+        parent[i] = root;
+    }
+    parent[i]
+}
+
+fn union(parent: &mut [usize], rank: &mut [usize], a: usize, b: usize) -> bool {
+    let mut ra = find(parent, a);
+    let mut rb = find(parent, b);
+    if ra == rb {
+        return false;
+    }
+
+    if rank[ra] < rank[rb] {
+        std::mem::swap(&mut ra, &mut rb);
+    }
+
+    parent[rb] = ra;
+    if rank[ra] == rank[rb] {
+        rank[ra] += 1;
+    }
+    true
+}
+
+fn edges_weighted_by_distance(points: &[Vertex]) -> Vec<Edge> {
+    let mut items: Vec<_> = Vec::new();
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let distance = points[i].distance_from(points[j]);
+            items.push(((points[i], points[j]), distance));
+        }
+    }
+    items.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+    items.iter().map(|(j, _)| *j).collect()
+}
+
+fn calculate_last_mst_forest_edge(edges: &[Edge]) -> Edge {
+    let mut id: HashMap<Vertex, usize> = HashMap::new();
+    let mut next = 0usize;
+
+    for &(u, v) in edges {
+        for vert in [u, v] {
+            id.entry(vert).or_insert_with(|| {
+                let idx = next;
+                next += 1;
+                idx
+            });
+        }
+    }
+
+    let mut parent: Vec<usize> = (0..next).collect();
+    let mut rank = vec![0; next];
+
+    let mut used = 0usize;
+
+    for &(u, v) in edges {
+        if union(&mut parent, &mut rank, id[&u], id[&v]) {
+            used += 1;
+            if used == next - 1 {
+                return (u, v);
+            }
+        }
+    }
+    unreachable!();
+}
+
+/// The connection that joins every junction box into a single circuit.
+pub fn calculate_last_edge(vertices: &[Vertex]) -> Edge {
+    let edges = edges_weighted_by_distance(vertices);
+    calculate_last_mst_forest_edge(&edges)
+}
+
+pub fn calculate_last_edge_x_product(vertices: &[Vertex]) -> usize {
+    let last_edge = calculate_last_edge(vertices);
+    last_edge.1.x * last_edge.0.x
+}
@@ -0,0 +1,59 @@
+use aoc_common::{ParseError, parse_fields, read_input};
+use std::{error::Error, io::BufRead};
+
+pub type Range = (u64, u64);
+pub type Ranges = Vec<Range>;
+pub type Id = u64;
+pub type Ids = Vec<Id>;
+
+pub fn calculate_total_fresh_ingredients(ranges: &[Range], ids: &[Id]) -> u64 {
+    ids.iter()
+        .map(|id| {
+            ranges
+                .iter()
+                .any(|(lower, upper)| id >= lower && id <= upper)
+        })
+        .filter(|is_fresh| *is_fresh)
+        .map(|_| 1)
+        .sum()
+}
+
+pub fn parse(input: &str) -> Result<(Ranges, Ids), ParseError> {
+    let mut it = input.lines().enumerate();
+    let ranges = it
+        .by_ref()
+        .take_while(|(_, l)| !l.is_empty())
+        .map(|(i, l)| {
+            let [lower, upper] = parse_fields::<Id, 2>(i, l, '-')?;
+            if lower > upper {
+                let (_, raw) = l.split_once('-').unwrap_or_default();
+                return Err(ParseError::at(
+                    format!("Range ends before it starts (raw: {:?})", l),
+                    i,
+                    l,
+                    raw,
+                ));
+            }
+            Ok((lower, upper))
+        })
+        .collect::<Result<Ranges, ParseError>>()?;
+    if ranges.is_empty() {
+        let first = input.lines().next().unwrap_or_default();
+        return Err(ParseError::whole_line(
+            "Expected at least one fresh ID range",
+            0,
+            first,
+        ));
+    }
+    let ids = it
+        .map(|(i, l)| {
+            l.parse::<Id>()
+                .map_err(|e| ParseError::whole_line(format!("Invalid ingredient id: {}", e), i, l))
+        })
+        .collect::<Result<Ids, ParseError>>()?;
+    Ok((ranges, ids))
+}
+
+pub fn read(reader: impl BufRead) -> Result<(Ranges, Ids), Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
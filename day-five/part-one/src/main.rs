@@ -39,58 +39,15 @@ So, in this example, 3 of the available ingredient IDs are fresh.
 Process the database file from the new inventory management system. How many of the available ingredient IDs are fresh?
 */
 
-use std::{
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-    num::ParseIntError,
-};
-
-type Range = (u64, u64);
-type Ranges = Vec<Range>;
-type Id = u64;
-type Ids = Vec<Id>;
-
-fn calculate_total_fresh_ingredients(ranges: Ranges, ids: Ids) -> u64 {
-    ids.iter()
-        .map(|id| {
-            ranges
-                .iter()
-                .any(|(lower, upper)| id >= lower && id <= upper)
-        })
-        .filter(|is_fresh| *is_fresh)
-        .map(|_| 1)
-        .sum()
-}
-
-fn read_file(file_path: &str) -> Result<(Ranges, Ids), Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<_>, _>>()?;
-    let mut it = lines.into_iter();
-    let ranges = it
-        .by_ref()
-        .take_while(|l| !l.is_empty())
-        .map(|l| {
-            let mut range = l.split("-");
-            let lower: u64 = range.next().unwrap_or("0").parse()?;
-            let upper: u64 = range.next().unwrap_or("0").parse()?;
-            Ok::<(u64, u64), ParseIntError>((lower, upper))
-        })
-        .collect::<Result<Vec<(u64, u64)>, ParseIntError>>()
-        .map_err(Box::new)?;
-    let ids = it
-        .map(|l| l.parse::<u64>())
-        .collect::<Result<Vec<u64>, ParseIntError>>()
-        .map_err(Box::new)?;
-    Ok((ranges, ids))
-}
+use aoc_common::InputSource;
+use aoc5pt1::{Ids, Ranges, calculate_total_fresh_ingredients, read};
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc5pt1 <input-file>");
-    let (ranges, ids): (Ranges, Ids) = read_file(&path)?;
-    let total = calculate_total_fresh_ingredients(ranges, ids);
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc5pt1 <input-file | - | --input-str <input>>");
+    let (ranges, ids): (Ranges, Ids) = source.read(read)?;
+    let total = calculate_total_fresh_ingredients(&ranges, &ids);
     println!("final = {total}");
     Ok(())
 }
@@ -0,0 +1,22 @@
+use aoc5pt1::Range;
+
+pub fn calculate_total_fresh_ingredients(ranges: &[Range]) -> u64 {
+    let mut fresh_ids = ranges.to_vec();
+    fresh_ids.sort_unstable_by_key(|(a, _)| *a);
+
+    let mut total = 0u64;
+    let mut cur_start = fresh_ids[0].0;
+    let mut cur_end = fresh_ids[0].1;
+
+    for (start, end) in fresh_ids.iter().skip(1) {
+        if *start > cur_end {
+            total += cur_end - cur_start + 1;
+            (cur_start, cur_end) = (*start, *end);
+        } else {
+            cur_end = cur_end.max(*end);
+        }
+    }
+
+    total += cur_end - cur_start + 1;
+    total
+}
@@ -0,0 +1,23 @@
+use aoc5pt1::Range;
+use aoc5pt2::calculate_total_fresh_ingredients;
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+fn collect_every_id(ranges: &[Range]) -> u64 {
+    ranges
+        .iter()
+        .flat_map(|&(lower, upper)| lower..=upper)
+        .collect::<HashSet<_>>()
+        .len() as u64
+}
+
+fn range() -> impl Strategy<Value = Range> {
+    (0u64..500, 0u64..50).prop_map(|(lower, span)| (lower, lower + span))
+}
+
+proptest! {
+    #[test]
+    fn matches_collecting_every_id(ranges in prop::collection::vec(range(), 1..20)) {
+        prop_assert_eq!(calculate_total_fresh_ingredients(&ranges), collect_every_id(&ranges));
+    }
+}
@@ -0,0 +1,62 @@
+use aoc_common::{ParseError, read_input};
+use std::{error::Error, io::BufRead};
+
+pub fn calculate_total_rolls(rolls: &[Vec<bool>]) -> u64 {
+    let mut reachable_rolls = 0;
+    for i in 0..rolls.len() {
+        for j in 0..rolls[i].len() {
+            if !rolls[i][j] {
+                continue;
+            }
+
+            let surrounding = (i > 0 && j > 0 && rolls[i - 1][j - 1]) as u64
+                + (i > 0 && rolls[i - 1][j]) as u64
+                + (i > 0 && j < rolls[i].len() - 1 && rolls[i - 1][j + 1]) as u64
+                + (j > 0 && rolls[i][j - 1]) as u64
+                + (j < rolls[i].len() - 1 && rolls[i][j + 1]) as u64
+                + (i < rolls.len() - 1 && j > 0 && rolls[i + 1][j - 1]) as u64
+                + (i < rolls.len() - 1 && rolls[i + 1][j]) as u64
+                + (i < rolls.len() - 1 && j < rolls[i].len() - 1 && rolls[i + 1][j + 1]) as u64;
+            if surrounding < 4 {
+                reachable_rolls += 1;
+            }
+        }
+    }
+    reachable_rolls
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<bool>>, ParseError> {
+    let mut width = None;
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, l)| {
+            if let Some((j, c)) = l.char_indices().find(|(_, c)| !matches!(c, '@' | '.')) {
+                return Err(ParseError::new(
+                    format!("Invalid grid cell (raw: {:?})", c),
+                    i,
+                    l,
+                    j..j + c.len_utf8(),
+                ));
+            }
+            match width {
+                Some(w) if w != l.len() => {
+                    return Err(ParseError::whole_line(
+                        format!("Expected a row of width {}, found {}", w, l.len()),
+                        i,
+                        l,
+                    ));
+                }
+                _ => width = Some(l.len()),
+            }
+            Ok(l.as_bytes()
+                .iter()
+                .map(|b| *b == b'@')
+                .collect::<Vec<bool>>())
+        })
+        .collect()
+}
+
+pub fn read(reader: impl BufRead) -> Result<Vec<Vec<bool>>, Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
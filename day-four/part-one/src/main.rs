@@ -43,57 +43,15 @@ Consider your complete diagram of the paper roll locations. How many rolls of pa
 
 */
 
-use std::{
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-fn calculate_total_rolls(rolls: Vec<Vec<bool>>) -> u64 {
-    let mut reachable_rolls = 0;
-    for i in 0..rolls.len() {
-        for j in 0..rolls[i].len() {
-            if !rolls[i][j] {
-                continue;
-            }
-
-            let surrounding = (i > 0 && j > 0 && rolls[i - 1][j - 1]) as u64
-                + (i > 0 && rolls[i - 1][j]) as u64
-                + (i > 0 && j < rolls[i].len() - 1 && rolls[i - 1][j + 1]) as u64
-                + (j > 0 && rolls[i][j - 1]) as u64
-                + (j < rolls[i].len() - 1 && rolls[i][j + 1]) as u64
-                + (i < rolls.len() - 1 && j > 0 && rolls[i + 1][j - 1]) as u64
-                + (i < rolls.len() - 1 && rolls[i + 1][j]) as u64
-                + (i < rolls.len() - 1 && j < rolls[i].len() - 1 && rolls[i + 1][j + 1]) as u64;
-            if surrounding < 4 {
-                reachable_rolls += 1;
-            }
-        }
-    }
-    reachable_rolls
-}
-
-fn read_file(file_path: &str) -> Result<Vec<Vec<bool>>, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<String>, _>>()?;
-    Ok(lines
-        .iter()
-        .map(|l| {
-            l.to_string()
-                .as_bytes()
-                .iter()
-                .map(|b| *b == b'@')
-                .collect::<Vec<bool>>()
-        })
-        .collect::<Vec<Vec<bool>>>())
-}
+use aoc_common::InputSource;
+use aoc4pt1::{calculate_total_rolls, read};
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc4pt2 <input-file>");
-    let roll_layout: Vec<Vec<bool>> = read_file(&path)?;
-    let total = calculate_total_rolls(roll_layout);
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc4pt1 <input-file | - | --input-str <input>>");
+    let roll_layout: Vec<Vec<bool>> = source.read(read)?;
+    let total = calculate_total_rolls(&roll_layout);
     println!("final = {total}");
     Ok(())
 }
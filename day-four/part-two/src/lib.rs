@@ -0,0 +1,35 @@
+pub fn calculate_total_rolls(rolls: &[Vec<bool>]) -> u64 {
+    let mut rolls = rolls.to_vec();
+    let mut reachable_rolls = 0;
+    let mut removed = true;
+    let mut marked_for_removal: Vec<(usize, usize)> = Vec::new();
+    while removed {
+        removed = false;
+        for i in 0..rolls.len() {
+            for j in 0..rolls[i].len() {
+                if !rolls[i][j] {
+                    continue;
+                }
+
+                let surrounding = (i > 0 && j > 0 && rolls[i - 1][j - 1]) as u64
+                    + (i > 0 && rolls[i - 1][j]) as u64
+                    + (i > 0 && j < rolls[i].len() - 1 && rolls[i - 1][j + 1]) as u64
+                    + (j > 0 && rolls[i][j - 1]) as u64
+                    + (j < rolls[i].len() - 1 && rolls[i][j + 1]) as u64
+                    + (i < rolls.len() - 1 && j > 0 && rolls[i + 1][j - 1]) as u64
+                    + (i < rolls.len() - 1 && rolls[i + 1][j]) as u64
+                    + (i < rolls.len() - 1 && j < rolls[i].len() - 1 && rolls[i + 1][j + 1]) as u64;
+                if surrounding < 4 {
+                    reachable_rolls += 1;
+                    marked_for_removal.push((i, j));
+                    removed = true;
+                }
+            }
+        }
+        for (i, j) in marked_for_removal.iter() {
+            rolls[*i][*j] = false;
+        }
+        marked_for_removal.clear();
+    }
+    reachable_rolls
+}
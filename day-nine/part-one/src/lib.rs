@@ -0,0 +1,72 @@
+use aoc_common::{ParseError, parse_fields, read_input};
+use std::{collections::HashMap, error::Error, io::BufRead};
+
+pub type Vertex = (i64, i64);
+
+/// Opposite corners of a rectangle.
+pub type Corners = (Vertex, Vertex);
+
+/// The corners and area of the largest rectangle, if any has a positive area.
+pub fn get_largest_rectangle(vertices: &[Vertex]) -> Option<(Corners, i64)> {
+    let mut largest = None;
+    let mut largest_area = 0;
+    for i in 0..vertices.len() {
+        for j in i + 1..vertices.len() {
+            let v1 = vertices[i];
+            let v2 = vertices[j];
+            let area = ((v1.0 - v2.0 + 1) * (v1.1 - v2.1 + 1)).abs();
+            if area > largest_area {
+                largest_area = area;
+                largest = Some(((v1, v2), area));
+            }
+        }
+    }
+    largest
+}
+
+pub fn get_largest_rectangle_area(vertices: &[Vertex]) -> i64 {
+    get_largest_rectangle(vertices).map_or(0, |(_, area)| area)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vertex>, ParseError> {
+    let vertices: Vec<Vertex> = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let [x, y] = parse_fields(i, line, ',')?;
+            Ok((x, y))
+        })
+        .collect::<Result<_, ParseError>>()?;
+    if vertices.len() < 2 {
+        let first = input.lines().next().unwrap_or_default();
+        return Err(ParseError::whole_line(
+            "Expected at least two red tiles",
+            0,
+            first,
+        ));
+    }
+    // The tiles make a loop of straight lines, so each one shares its row
+    // with one neighbour and its column with the other.
+    let (mut rows, mut columns) = (HashMap::new(), HashMap::new());
+    for (x, y) in &vertices {
+        *rows.entry(y).or_insert(0) += 1;
+        *columns.entry(x).or_insert(0) += 1;
+    }
+    for (i, (line, (x, y))) in input.lines().zip(&vertices).enumerate() {
+        let missing = match (rows[y], columns[x]) {
+            (1, _) => "row",
+            (_, 1) => "column",
+            _ => continue,
+        };
+        return Err(ParseError::whole_line(
+            format!("Expected another red tile in the same {missing}"),
+            i,
+            line,
+        ));
+    }
+    Ok(vertices)
+}
+
+pub fn read(reader: impl BufRead) -> Result<Vec<Vertex>, Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
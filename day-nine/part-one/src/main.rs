@@ -1,48 +1,11 @@
-use std::{
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-type Vertex = (i64, i64);
-
-fn get_largest_rectangle_area(vertices: &[Vertex]) -> i64 {
-    let mut largest_area = 0;
-    for i in 0..vertices.len() {
-        for j in i + 1..vertices.len() {
-            let v1 = vertices[i];
-            let v2 = vertices[j];
-            let area = ((v1.0 - v2.0 + 1) * (v1.1 - v2.1 + 1)).abs();
-            if area > largest_area {
-                largest_area = area;
-            }
-        }
-    }
-    largest_area
-}
-
-fn read_file(file_path: &str) -> Result<Vec<Vertex>, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<_>, _>>()?;
-
-    let points: Vec<Vertex> = lines
-        .iter()
-        .map(|line| {
-            let mut split = line.split(',');
-            let x = split.next().unwrap().parse().unwrap();
-            let y = split.next().unwrap().parse().unwrap();
-            (x, y)
-        })
-        .collect();
-
-    Ok(points)
-}
+use aoc_common::InputSource;
+use aoc9pt1::{Vertex, get_largest_rectangle_area, read};
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc9pt1 <input-file>");
-    let vertices: Vec<Vertex> = read_file(&path)?;
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc9pt1 <input-file | - | --input-str <input>>");
+    let vertices: Vec<Vertex> = source.read(read)?;
     let total = get_largest_rectangle_area(&vertices);
     println!("final = {total}");
     Ok(())
@@ -0,0 +1,150 @@
+use crate::trace::TraceStep;
+use std::{
+    f64::consts::TAU,
+    io::{self, Write},
+    thread,
+    time::Duration,
+};
+
+/// Rows from the ring's centre to its top; columns are twice as many, since
+/// terminal cells are about twice as tall as they are wide.
+const RADIUS: usize = 6;
+/// At most this many marks go round the ring, each standing for an equal
+/// share of the dial's positions.
+const MAX_TICKS: u64 = 40;
+
+/// Ends each frame of a headless dump.
+pub const FRAME_END: &str = "----";
+
+/// Draws the dial as a ring of `.` marks with `0` at the top, or `*` when
+/// `step` reached zero, and the pointer as `@`, followed by what `step` did
+/// and the running `password`. `step` is `None` before the first combo.
+pub fn frame(positions: u64, position: u64, step: Option<&TraceStep>, password: u64) -> String {
+    let ticks = positions.min(MAX_TICKS);
+    let tick_of =
+        |click: u64| (u128::from(click) * u128::from(ticks) / u128::from(positions)) as u64;
+    let (rows, cols) = (2 * RADIUS + 1, 4 * RADIUS + 1);
+    let mut grid = vec![vec![' '; cols]; rows];
+    let mut mark = |tick: u64, c: char| {
+        let angle = TAU * tick as f64 / ticks as f64;
+        let row = RADIUS as f64 - angle.cos() * RADIUS as f64;
+        let col = 2.0 * RADIUS as f64 + angle.sin() * 2.0 * RADIUS as f64;
+        grid[row.round() as usize][col.round() as usize] = c;
+    };
+
+    for tick in 1..ticks {
+        mark(tick, '.');
+    }
+    let reached_zero = step.is_some_and(|step| step.zero_clicks.count() > 0);
+    mark(0, if reached_zero { '*' } else { '0' });
+    mark(tick_of(position), '@');
+
+    let mut lines: Vec<String> = Vec::with_capacity(rows + 3);
+    lines.push(match step {
+        Some(step) => format!(
+            "line {}: {}  {} -> {}",
+            step.line, step.combo, step.before, step.after
+        ),
+        None => format!("start at {position}"),
+    });
+    let centre = position.to_string();
+    for (i, row) in grid.iter_mut().enumerate() {
+        if i == RADIUS && centre.len() < cols - 4 {
+            let at = (cols - centre.len()) / 2;
+            row.splice(at..at + centre.len(), centre.chars());
+        }
+        lines.push(row.iter().collect::<String>().trim_end().to_owned());
+    }
+    if let Some(step) = step {
+        let clicks = match step.zero_clicks.count() {
+            0 => "none".to_owned(),
+            _ => step.zero_clicks.to_string(),
+        };
+        let landed = if step.after == 0 { " (landed)" } else { "" };
+        lines.push(format!("zero clicks: {clicks}{landed}"));
+        lines.push(format!("password = {password} (+{})", step.zeros));
+    } else {
+        lines.push(format!("password = {password}"));
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Where [`animate`] shows its frames.
+pub enum Screen<W> {
+    /// Redraws in place, pausing between frames.
+    Terminal { out: W, delay: Duration },
+    /// Writes every frame one after another, each followed by a
+    /// [`FRAME_END`] line, so they can be compared as text.
+    Headless { out: W },
+}
+
+/// Shows the dial before and after each of `steps`.
+pub fn animate(
+    screen: &mut Screen<impl Write>,
+    positions: u64,
+    start: u64,
+    steps: &[TraceStep],
+) -> io::Result<()> {
+    let mut password = 0;
+    show(screen, &frame(positions, start, None, password))?;
+    for step in steps {
+        password += step.zeros;
+        show(screen, &frame(positions, step.after, Some(step), password))?;
+    }
+    match screen {
+        Screen::Terminal { out, .. } | Screen::Headless { out } => out.flush(),
+    }
+}
+
+fn show(screen: &mut Screen<impl Write>, frame: &str) -> io::Result<()> {
+    match screen {
+        Screen::Terminal { out, delay } => {
+            write!(out, "\x1b[2J\x1b[H{frame}")?;
+            out.flush()?;
+            thread::sleep(*delay);
+        }
+        Screen::Headless { out } => writeln!(out, "{frame}{FRAME_END}")?,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ZeroPolicy, calculate_password_traced, parse};
+
+    #[test]
+    fn dumps_a_frame_per_combo() {
+        let rotations = parse("L68\nL30\nR48\n").unwrap();
+        let mut steps = Vec::new();
+        calculate_password_traced(&rotations, ZeroPolicy::PassesThrough, |step| {
+            steps.push(step)
+        })
+        .unwrap();
+        let mut screen = Screen::Headless { out: Vec::new() };
+        animate(&mut screen, 100, 50, &steps).unwrap();
+        let Screen::Headless { out } = screen else {
+            unreachable!()
+        };
+        let frames = String::from_utf8(out).unwrap();
+        let frames: Vec<&str> = frames.split_terminator("----\n").collect();
+        let top = |frame: &str| {
+            frame
+                .lines()
+                .nth(1)
+                .unwrap()
+                .chars()
+                .nth(2 * RADIUS)
+                .unwrap()
+        };
+
+        assert_eq!(frames.len(), 4);
+        assert!(frames[0].starts_with("start at 50\n"));
+        assert!(frames[1].contains("zero clicks: 50\n"));
+        assert_eq!(top(frames[1]), '*');
+        assert!(frames[2].contains("zero clicks: none\n"));
+        assert_eq!(top(frames[2]), '0');
+        assert_eq!(top(frames[3]), '@');
+        assert!(frames[3].ends_with("zero clicks: 48 (landed)\npassword = 2 (+1)\n"));
+    }
+}
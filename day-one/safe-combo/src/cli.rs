@@ -0,0 +1,138 @@
+use crate::{
+    DIAL_POSITIONS, PasswordCounter, START_ROT, ZeroPolicy,
+    animate::{Screen, animate},
+    calculate_password_streamed, stream,
+    trace::{TraceFormat, write_trace},
+};
+use aoc_common::InputSource;
+use std::{
+    env,
+    error::Error,
+    fs::File,
+    io::{self, BufWriter, Write},
+    iter::Peekable,
+    path::PathBuf,
+    time::Duration,
+};
+
+const DEFAULT_DELAY_MS: u64 = 200;
+
+/// Flags the day one binaries take ahead of their input.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Options {
+    pub trace: Option<TraceFormat>,
+    pub animate: Option<Animation>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Animation {
+    pub delay: Duration,
+    /// Dump frames here instead of drawing them in the terminal.
+    pub frames: Option<PathBuf>,
+}
+
+impl Options {
+    /// Takes `--trace <table | csv>`, `--animate`, `--delay <ms>` and
+    /// `--frames <file>` from the front of `args`, stopping at the input.
+    /// `--delay` and `--frames` imply `--animate`.
+    pub fn from_args(
+        args: &mut Peekable<impl Iterator<Item = String>>,
+    ) -> Result<Self, Box<dyn Error>> {
+        let mut options = Self::default();
+        while let Some(flag) = args.next_if(|arg| arg.starts_with("--") && arg != "--input-str") {
+            let mut value = || args.next().ok_or(format!("missing value for {flag}"));
+            match flag.as_str() {
+                "--trace" => options.trace = Some(value()?.parse()?),
+                "--animate" => {
+                    options.animation();
+                }
+                "--delay" => {
+                    let ms = value()?;
+                    let ms = ms
+                        .parse()
+                        .map_err(|_| format!("invalid delay (raw: {ms:?})"))?;
+                    options.animation().delay = Duration::from_millis(ms);
+                }
+                "--frames" => options.animation().frames = Some(value()?.into()),
+                _ => return Err(format!("unknown flag {flag}").into()),
+            }
+        }
+        Ok(options)
+    }
+
+    fn animation(&mut self) -> &mut Animation {
+        self.animate.get_or_insert(Animation {
+            delay: Duration::from_millis(DEFAULT_DELAY_MS),
+            frames: None,
+        })
+    }
+}
+
+/// The whole of a day one binary, counting zeros under `policy`.
+pub fn main(policy: ZeroPolicy, usage: &str) -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1).peekable();
+    let options = Options::from_args(&mut args)?;
+    let source = InputSource::from_args(args).expect(usage);
+
+    let mut steps = Vec::new();
+    let keep_steps = options.trace.is_some() || options.animate.is_some();
+    let PasswordCounter { total, password } =
+        calculate_password_streamed(stream(source.reader()?), policy, |step| {
+            if keep_steps {
+                steps.push(step);
+            }
+        })
+        .map_err(|e| source.label(e))?;
+
+    if let Some(format) = options.trace {
+        write_trace(io::stdout().lock(), &steps, format)?;
+    }
+    if let Some(Animation { delay, frames }) = options.animate {
+        let mut screen: Screen<Box<dyn Write>> = match frames {
+            Some(path) => Screen::Headless {
+                out: Box::new(BufWriter::new(File::create(&path).map_err(|e| {
+                    io::Error::new(e.kind(), format!("{}: {}", path.display(), e))
+                })?)),
+            },
+            None => Screen::Terminal {
+                out: Box::new(io::stdout().lock()),
+                delay,
+            },
+        };
+        animate(&mut screen, DIAL_POSITIONS, START_ROT, &steps)?;
+    }
+    println!("final = {total}, password = {password}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(args: &[&str]) -> Result<(Options, Vec<String>), String> {
+        let mut args = args.iter().map(|a| a.to_string()).peekable();
+        let options = Options::from_args(&mut args).map_err(|e| e.to_string())?;
+        Ok((options, args.collect()))
+    }
+
+    #[test]
+    fn takes_flags_up_to_the_input() {
+        let (opts, rest) = options(&["--trace", "csv", "--delay", "50", "in.txt"]).unwrap();
+        assert_eq!(opts.trace, Some(TraceFormat::Csv));
+        assert_eq!(
+            opts.animate,
+            Some(Animation {
+                delay: Duration::from_millis(50),
+                frames: None,
+            })
+        );
+        assert_eq!(rest, ["in.txt"]);
+
+        let (opts, rest) = options(&["--input-str", "L1"]).unwrap();
+        assert_eq!(opts, Options::default());
+        assert_eq!(rest, ["--input-str", "L1"]);
+
+        assert!(options(&["--frames"]).is_err());
+        assert!(options(&["--spin", "-"]).is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use std::error::Error;
 use std::fmt;
-use std::ops;
+use std::num::{IntErrorKind, ParseIntError};
 use std::str::FromStr;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -12,7 +12,17 @@ pub enum ComboDirection {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct SafeCombo {
     pub direction: ComboDirection,
-    pub steps: i16,
+    pub steps: u64,
+}
+
+impl fmt::Display for SafeCombo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            ComboDirection::L => 'L',
+            ComboDirection::R => 'R',
+        };
+        write!(f, "{}{}", direction, self.steps)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -20,6 +30,7 @@ pub enum SafeComboParsingError {
     Empty { raw: String },
     InvalidDirection { raw: String },
     InvalidRotation { raw: String },
+    RotationOutOfRange { raw: String },
 }
 
 impl fmt::Display for SafeComboParsingError {
@@ -34,6 +45,9 @@ impl fmt::Display for SafeComboParsingError {
             SafeComboParsingError::InvalidRotation { raw } => {
                 write!(f, "invalid rotation steps (raw: {:?})", raw)
             }
+            SafeComboParsingError::RotationOutOfRange { raw } => {
+                write!(f, "rotation steps exceed {} (raw: {:?})", u64::MAX, raw)
+            }
         }
     }
 }
@@ -50,30 +64,22 @@ impl FromStr for SafeCombo {
             return Err(SafeComboParsingError::Empty { raw });
         }
 
-        let (direction_raw, steps_raw) = s.split_at(1);
+        let direction_len = s.chars().next().map_or(0, char::len_utf8);
+        let (direction_raw, steps_raw) = s.split_at(direction_len);
         let direction = match direction_raw {
             "L" | "l" => ComboDirection::L,
             "R" | "r" => ComboDirection::R,
             _ => return Err(SafeComboParsingError::InvalidDirection { raw }),
         };
 
-        let steps: i16 = steps_raw
-            .parse()
-            .map_err(|_| SafeComboParsingError::InvalidRotation {
-                raw: steps_raw.to_owned(),
-            })?;
+        let steps: u64 = steps_raw.parse().map_err(|e: ParseIntError| {
+            let raw = steps_raw.to_owned();
+            match e.kind() {
+                IntErrorKind::PosOverflow => SafeComboParsingError::RotationOutOfRange { raw },
+                _ => SafeComboParsingError::InvalidRotation { raw },
+            }
+        })?;
 
         Ok(Self { direction, steps })
     }
 }
-
-impl ops::Add<&SafeCombo> for i16 {
-    type Output = i16;
-
-    fn add(self, _rhs: &SafeCombo) -> Self::Output {
-        match _rhs.direction {
-            ComboDirection::L => (self - _rhs.steps) % 100,
-            ComboDirection::R => (self + _rhs.steps) % 100,
-        }
-    }
-}
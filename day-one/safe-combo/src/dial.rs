@@ -0,0 +1,56 @@
+use crate::{combo::SafeCombo, position::Position};
+
+/// Which clicks a [`Dial`] counts as reaching zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ZeroPolicy {
+    /// Only rotations that end on zero.
+    LandsOn,
+    /// Every click that lands on zero, including mid-rotation.
+    PassesThrough,
+}
+
+/// A dial of `positions` clicks, numbered from zero, counting how often it
+/// reaches zero under its [`ZeroPolicy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    position: Position,
+    policy: ZeroPolicy,
+    zeros: u64,
+}
+
+impl Dial {
+    pub fn new(positions: u64, start: u64, policy: ZeroPolicy) -> Self {
+        Self {
+            position: Position::new(start, positions),
+            policy,
+            zeros: 0,
+        }
+    }
+
+    pub fn positions(&self) -> u64 {
+        self.position.positions()
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position.value()
+    }
+
+    pub fn zeros(&self) -> u64 {
+        self.zeros
+    }
+
+    /// Turns the dial by `combo`, or `None` if the zero count would
+    /// overflow.
+    pub fn checked_add(self, combo: &SafeCombo) -> Option<Self> {
+        let position = self.position + combo;
+        let zeros = match self.policy {
+            ZeroPolicy::LandsOn => u64::from(position.is_zero()),
+            ZeroPolicy::PassesThrough => self.position.zeros_passed(combo),
+        };
+        Some(Dial {
+            position,
+            zeros: self.zeros.checked_add(zeros)?,
+            ..self
+        })
+    }
+}
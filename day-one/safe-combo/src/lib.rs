@@ -0,0 +1,142 @@
+pub mod animate;
+pub mod cli;
+pub mod combo;
+pub mod dial;
+pub mod lock;
+pub mod position;
+pub mod program;
+pub mod stream;
+pub mod synth;
+pub mod trace;
+use aoc_common::{ParseError, read_input};
+pub use combo::{ComboDirection, SafeCombo, SafeComboParsingError};
+pub use dial::{Dial, ZeroPolicy};
+pub use lock::Lock;
+pub use position::Position;
+pub use program::{Instruction, Program, ProgramParsingError, Rotations, Statement};
+use std::{error::Error, fmt, io::BufRead};
+pub use stream::{Stream, stream};
+pub use synth::{SynthesisError, Target, synthesize};
+pub use trace::{TraceFormat, TraceStep, ZeroClicks, write_trace};
+
+pub const DIAL_POSITIONS: u64 = 100;
+pub const START_ROT: u64 = 50;
+
+/// Where the dial stopped and how often it reached zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PasswordCounter {
+    pub total: u64,
+    pub password: u64,
+}
+
+/// The zero count no longer fits in a `u64` after a combo on `line`
+/// (1-based).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PasswordOverflow {
+    pub line: usize,
+}
+
+impl fmt::Display for PasswordOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "password overflows {} on line {}", u64::MAX, self.line)
+    }
+}
+
+impl Error for PasswordOverflow {}
+
+/// Part one counts with [`ZeroPolicy::LandsOn`], part two with
+/// [`ZeroPolicy::PassesThrough`].
+pub fn calculate_password(
+    input: &Rotations,
+    policy: ZeroPolicy,
+) -> Result<PasswordCounter, PasswordOverflow> {
+    calculate_password_traced(input, policy, |_| {})
+}
+
+/// Like [`calculate_password`], handing `trace` each combo's effect on the
+/// dial as it goes.
+pub fn calculate_password_traced(
+    input: &Rotations,
+    policy: ZeroPolicy,
+    trace: impl FnMut(TraceStep),
+) -> Result<PasswordCounter, PasswordOverflow> {
+    fold_password(
+        input.with_lines().map(|(line, combo)| Ok((line, *combo))),
+        policy,
+        trace,
+    )
+}
+
+/// Like [`calculate_password`] straight from a [`Program`], unrolling its
+/// repeats one combo at a time rather than up front.
+pub fn calculate_program_password(
+    program: &Program,
+    policy: ZeroPolicy,
+) -> Result<PasswordCounter, PasswordOverflow> {
+    fold_password(program.combos(START_ROT).map(Ok), policy, |_| {})
+}
+
+/// Like [`calculate_password_traced`] over combos as they arrive, such as
+/// from [`stream`], stopping at the first error.
+pub fn calculate_password_streamed(
+    combos: impl IntoIterator<Item = Result<(usize, SafeCombo), Box<dyn Error>>>,
+    policy: ZeroPolicy,
+    trace: impl FnMut(TraceStep),
+) -> Result<PasswordCounter, Box<dyn Error>> {
+    fold_password(combos.into_iter(), policy, trace)
+}
+
+fn fold_password<E: From<PasswordOverflow>>(
+    mut combos: impl Iterator<Item = Result<(usize, SafeCombo), E>>,
+    policy: ZeroPolicy,
+    mut trace: impl FnMut(TraceStep),
+) -> Result<PasswordCounter, E> {
+    let init = Dial::new(DIAL_POSITIONS, START_ROT, policy);
+    let dial = combos.try_fold(init, |dial, combo| -> Result<Dial, E> {
+        let (line, combo) = combo?;
+        let turned = dial.checked_add(&combo).ok_or(PasswordOverflow { line })?;
+        let before = Position::new(dial.position(), dial.positions());
+        trace(TraceStep {
+            line,
+            combo,
+            before: dial.position(),
+            after: turned.position(),
+            full_turns: before.full_turns(&combo),
+            zeros: turned.zeros() - dial.zeros(),
+            zero_clicks: ZeroClicks::new(before, &combo),
+        });
+        Ok(turned)
+    })?;
+    Ok(PasswordCounter {
+        total: dial.position(),
+        password: dial.zeros(),
+    })
+}
+
+/// Like [`calculate_password`] for every wheel of `lock`, from the dial
+/// inwards.
+pub fn calculate_lock_passwords(
+    input: &Rotations,
+    lock: Lock,
+) -> Result<Vec<PasswordCounter>, PasswordOverflow> {
+    let lock = input.with_lines().try_fold(lock, |lock, (line, combo)| {
+        lock.checked_add(combo).ok_or(PasswordOverflow { line })
+    })?;
+    Ok(lock
+        .wheels()
+        .map(|dial| PasswordCounter {
+            total: dial.position(),
+            password: dial.zeros(),
+        })
+        .collect())
+}
+
+/// Parses a [`Program`] for the puzzle's dial and expands it from the
+/// puzzle's start.
+pub fn parse(input: &str) -> Result<Rotations, ParseError> {
+    Ok(Program::parse(input, DIAL_POSITIONS)?.expand(START_ROT))
+}
+
+pub fn read(reader: impl BufRead) -> Result<Rotations, Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
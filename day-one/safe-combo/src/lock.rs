@@ -0,0 +1,84 @@
+use crate::{
+    combo::{ComboDirection, SafeCombo},
+    dial::Dial,
+};
+
+/// A lock of several wheels behind one dial. Turning the dial turns the
+/// first wheel directly; each wheel's drive pin picks up the next wheel only
+/// after a full revolution in the same direction, and lets go of it when
+/// the direction changes. Driven wheels move click for click with the wheel
+/// driving them, whatever their sizes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lock {
+    wheels: Vec<Wheel>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Wheel {
+    dial: Dial,
+    pin: DrivePin,
+}
+
+/// How far a wheel still has to turn in `direction` before its pin engages
+/// the next wheel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DrivePin {
+    direction: Option<ComboDirection>,
+    slack: u64,
+}
+
+impl DrivePin {
+    /// Turns the pin's wheel by `combo`, returning how many of its clicks
+    /// carry the next wheel along.
+    fn drive(&mut self, combo: &SafeCombo, positions: u64) -> u64 {
+        if combo.steps == 0 {
+            return 0;
+        }
+        if self.direction != Some(combo.direction) {
+            self.direction = Some(combo.direction);
+            self.slack = positions;
+        }
+        let driven = combo.steps.saturating_sub(self.slack);
+        self.slack = self.slack.saturating_sub(combo.steps);
+        driven
+    }
+}
+
+impl Lock {
+    /// `dials` are the wheels from the dial inwards, each with its own size,
+    /// start and [`ZeroPolicy`](crate::ZeroPolicy). No pins start engaged.
+    pub fn new(dials: impl IntoIterator<Item = Dial>) -> Self {
+        let wheels: Vec<Wheel> = dials
+            .into_iter()
+            .map(|dial| Wheel {
+                dial,
+                pin: DrivePin {
+                    direction: None,
+                    slack: dial.positions(),
+                },
+            })
+            .collect();
+        assert!(!wheels.is_empty(), "a lock needs at least one wheel");
+        Self { wheels }
+    }
+
+    /// Each wheel's dial, from the dial inwards.
+    pub fn wheels(&self) -> impl Iterator<Item = &Dial> {
+        self.wheels.iter().map(|wheel| &wheel.dial)
+    }
+
+    /// Turns the dial by `combo`, or `None` if any wheel's zero count would
+    /// overflow. Wheels that are not driven don't move, so they count no
+    /// zeros even under [`ZeroPolicy::LandsOn`](crate::ZeroPolicy::LandsOn).
+    pub fn checked_add(mut self, combo: &SafeCombo) -> Option<Self> {
+        let mut turn = *combo;
+        for (i, wheel) in self.wheels.iter_mut().enumerate() {
+            if i > 0 && turn.steps == 0 {
+                break;
+            }
+            wheel.dial = wheel.dial.checked_add(&turn)?;
+            turn.steps = wheel.pin.drive(&turn, wheel.dial.positions());
+        }
+        Some(self)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::combo::{ComboDirection, SafeCombo};
+use std::ops;
+
+/// A click on a dial of `positions` clicks, always in `0..positions`, so
+/// turning left past zero wraps instead of going negative.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Position {
+    value: u64,
+    positions: u64,
+}
+
+impl Position {
+    pub fn new(value: u64, positions: u64) -> Self {
+        assert!(positions > 0, "a dial needs at least one position");
+        Self {
+            value: value % positions,
+            positions,
+        }
+    }
+
+    pub fn value(self) -> u64 {
+        self.value
+    }
+
+    pub fn positions(self) -> u64 {
+        self.positions
+    }
+
+    pub fn is_zero(self) -> bool {
+        self.value == 0
+    }
+
+    /// The shortest turn from here to `to`, turning right on a tie.
+    pub fn toward(self, to: u64) -> SafeCombo {
+        debug_assert!(to < self.positions, "{to} is off the dial");
+        let clockwise = |from: u64, to: u64| {
+            if to >= from {
+                to - from
+            } else {
+                self.positions - (from - to)
+            }
+        };
+        let (right, left) = (clockwise(self.value, to), clockwise(to, self.value));
+        if right <= left {
+            SafeCombo {
+                direction: ComboDirection::R,
+                steps: right,
+            }
+        } else {
+            SafeCombo {
+                direction: ComboDirection::L,
+                steps: left,
+            }
+        }
+    }
+
+    /// How many times `combo` takes the dial all the way round.
+    pub fn full_turns(self, combo: &SafeCombo) -> u64 {
+        combo.steps / self.positions
+    }
+
+    /// How many clicks of `combo` land on zero, including its last one.
+    pub fn zeros_passed(self, combo: &SafeCombo) -> u64 {
+        let rem = combo.steps % self.positions;
+        let full_turns = self.full_turns(combo);
+        let crosses = match combo.direction {
+            ComboDirection::L => rem >= self.value && self.value != 0,
+            ComboDirection::R => rem >= self.positions - self.value,
+        };
+        full_turns + u64::from(crosses)
+    }
+}
+
+impl ops::Add<&SafeCombo> for Position {
+    type Output = Position;
+
+    // Both `value` and the leftover turn are below `positions`, so wrapping
+    // by hand never overflows, even on a dial of `u64::MAX` clicks.
+    fn add(self, rhs: &SafeCombo) -> Self::Output {
+        let rem = rhs.steps % self.positions;
+        let value = match rhs.direction {
+            ComboDirection::L if rem <= self.value => self.value - rem,
+            ComboDirection::L => self.positions - (rem - self.value),
+            ComboDirection::R if rem < self.positions - self.value => self.value + rem,
+            ComboDirection::R => rem - (self.positions - self.value),
+        };
+        Position { value, ..self }
+    }
+}
@@ -0,0 +1,405 @@
+//! The day one input language. Each line holds whitespace-separated
+//! instructions, and anything after a `#` is a comment:
+//!
+//! ```text
+//! # warm up, then pass zero six times
+//! =37 L10
+//! 3x(L10 R20) R5
+//! ```
+//!
+//! Plain puzzle input, one `L<n>`/`R<n>` per line, is a program too.
+
+use crate::{
+    combo::{SafeCombo, SafeComboParsingError},
+    position::Position,
+};
+use aoc_common::ParseError;
+use std::{error::Error, fmt, iter, ops::Deref};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Instruction {
+    /// `L<n>` or `R<n>`.
+    Turn(SafeCombo),
+    /// `=<n>`, the shortest turn to position `n`.
+    Goto(u64),
+    /// `<n>x(...)`, the body `n` times over.
+    Repeat { times: u64, body: Vec<Instruction> },
+}
+
+/// The instructions on one line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Statement {
+    /// 1-based.
+    pub line: usize,
+    pub instructions: Vec<Instruction>,
+}
+
+/// A parsed program for a dial of `positions` clicks. Blank and comment-only
+/// lines leave no statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Program {
+    pub positions: u64,
+    pub statements: Vec<Statement>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProgramParsingError {
+    InvalidPosition { raw: String, positions: u64 },
+    InvalidRepeatCount { raw: String },
+    MissingRepeatBody { raw: String },
+    MissingRepeatCount { raw: String },
+    UnclosedRepeat { raw: String },
+    UnmatchedParen { raw: String },
+}
+
+impl fmt::Display for ProgramParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProgramParsingError::InvalidPosition { raw, positions } => write!(
+                f,
+                "invalid absolute position, expected 0 to {} (raw: {:?})",
+                positions - 1,
+                raw
+            ),
+            ProgramParsingError::InvalidRepeatCount { raw } => {
+                write!(f, "invalid repeat count (raw: {:?})", raw)
+            }
+            ProgramParsingError::MissingRepeatBody { raw } => {
+                write!(f, "expected '(' after repeat count (raw: {:?})", raw)
+            }
+            ProgramParsingError::MissingRepeatCount { raw } => {
+                write!(f, "expected a repeat count before '(' (raw: {:?})", raw)
+            }
+            ProgramParsingError::UnclosedRepeat { raw } => {
+                write!(f, "unclosed repeat block (raw: {:?})", raw)
+            }
+            ProgramParsingError::UnmatchedParen { raw } => {
+                write!(f, "unmatched ')' (raw: {:?})", raw)
+            }
+        }
+    }
+}
+
+impl Error for ProgramParsingError {}
+
+impl Program {
+    pub fn parse(input: &str, positions: u64) -> Result<Self, ParseError> {
+        let statements = input
+            .lines()
+            .enumerate()
+            .filter_map(|(i, text)| parse_statement(i, text, positions).transpose())
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            positions,
+            statements,
+        })
+    }
+
+    /// The combos the program turns the dial through from `start`, resolving
+    /// each `=<n>` against where the dial is by then. Repeats are unrolled
+    /// as the combos are taken, never all at once.
+    pub fn combos(&self, start: u64) -> impl Iterator<Item = (usize, SafeCombo)> + '_ {
+        let mut expander = Expander::new(Position::new(start, self.positions));
+        let mut statements = self.statements.iter();
+        iter::from_fn(move || {
+            loop {
+                if let Some(combo) = expander.next() {
+                    return Some(combo);
+                }
+                expander.load(statements.next()?.clone());
+            }
+        })
+    }
+
+    /// Unrolls the program into [`combos`](Self::combos) from `start`.
+    pub fn expand(&self, start: u64) -> Rotations {
+        let (lines, combos) = self.combos(start).unzip();
+        Rotations { combos, lines }
+    }
+}
+
+/// Parses line `i` (zero-based) of a program, or `None` if it holds no
+/// instructions.
+pub(crate) fn parse_statement(
+    i: usize,
+    text: &str,
+    positions: u64,
+) -> Result<Option<Statement>, ParseError> {
+    assert!(positions > 0, "a dial needs at least one position");
+    let mut parser = LineParser {
+        line: i,
+        text,
+        code: text.split('#').next().unwrap_or_default(),
+        pos: 0,
+        positions,
+    };
+    let instructions = parser.instructions(None)?;
+    Ok((!instructions.is_empty()).then_some(Statement {
+        line: i + 1,
+        instructions,
+    }))
+}
+
+/// Unrolls one statement at a time into combos, lazily, so a repeat block
+/// costs no more memory than its body however many times it runs.
+pub(crate) struct Expander {
+    position: Position,
+    line: usize,
+    /// The repeat blocks being run, innermost last.
+    stack: Vec<Frame>,
+}
+
+struct Frame {
+    body: Vec<Instruction>,
+    next: usize,
+    times_left: u64,
+}
+
+impl Expander {
+    pub(crate) fn new(start: Position) -> Self {
+        Self {
+            position: start,
+            line: 0,
+            stack: Vec::new(),
+        }
+    }
+
+    /// Queues `statement`, which the expander must have run dry of the last.
+    pub(crate) fn load(&mut self, statement: Statement) {
+        debug_assert!(self.stack.is_empty());
+        self.line = statement.line;
+        self.stack.push(Frame {
+            body: statement.instructions,
+            next: 0,
+            times_left: 1,
+        });
+    }
+}
+
+impl Iterator for Expander {
+    type Item = (usize, SafeCombo);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = self.stack.last_mut()?;
+            let Some(instruction) = frame.body.get(frame.next) else {
+                frame.times_left -= 1;
+                frame.next = 0;
+                if frame.times_left == 0 {
+                    self.stack.pop();
+                }
+                continue;
+            };
+            frame.next += 1;
+            let combo = match instruction {
+                Instruction::Turn(combo) => *combo,
+                Instruction::Goto(to) => self.position.toward(*to),
+                Instruction::Repeat { times, body } => {
+                    if *times > 0 && !body.is_empty() {
+                        let frame = Frame {
+                            body: body.clone(),
+                            next: 0,
+                            times_left: *times,
+                        };
+                        self.stack.push(frame);
+                    }
+                    continue;
+                }
+            };
+            self.position = self.position + &combo;
+            return Some((self.line, combo));
+        }
+    }
+}
+
+struct LineParser<'a> {
+    line: usize,
+    text: &'a str,
+    /// `text` up to any comment.
+    code: &'a str,
+    pos: usize,
+    positions: u64,
+}
+
+impl LineParser<'_> {
+    fn error(&self, e: impl fmt::Display, part: &str) -> ParseError {
+        ParseError::at(e, self.line, self.text, part)
+    }
+
+    /// Instructions up to the end of the line or, inside a repeat block
+    /// opened at `open`, up to and including its `)`.
+    fn instructions(&mut self, open: Option<usize>) -> Result<Vec<Instruction>, ParseError> {
+        let mut instructions = Vec::new();
+        loop {
+            self.pos = self.code.len() - self.code[self.pos..].trim_start().len();
+            let paren = &self.code[self.pos..];
+            match (paren.chars().next(), open) {
+                (None, None) => return Ok(instructions),
+                (None, Some(open)) => {
+                    let raw = &self.code[open..open + 1];
+                    let e = ProgramParsingError::UnclosedRepeat {
+                        raw: raw.to_owned(),
+                    };
+                    return Err(self.error(e, raw));
+                }
+                (Some(')'), Some(_)) => {
+                    self.pos += 1;
+                    return Ok(instructions);
+                }
+                (Some(')'), None) => {
+                    let raw = &paren[..1];
+                    let e = ProgramParsingError::UnmatchedParen {
+                        raw: raw.to_owned(),
+                    };
+                    return Err(self.error(e, raw));
+                }
+                _ => instructions.push(self.instruction()?),
+            }
+        }
+    }
+
+    fn instruction(&mut self) -> Result<Instruction, ParseError> {
+        let start = self.pos;
+        let rest = &self.code[start..];
+        let len = rest
+            .find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+            .unwrap_or(rest.len());
+        self.pos += len;
+        let word = &rest[..len];
+
+        if word.is_empty() {
+            let raw = &rest[..1];
+            let e = ProgramParsingError::MissingRepeatCount {
+                raw: raw.to_owned(),
+            };
+            return Err(self.error(e, raw));
+        }
+        if let Some(raw) = word.strip_prefix('=') {
+            return match raw.parse::<u64>() {
+                Ok(to) if to < self.positions => Ok(Instruction::Goto(to)),
+                _ => {
+                    let e = ProgramParsingError::InvalidPosition {
+                        raw: raw.to_owned(),
+                        positions: self.positions,
+                    };
+                    Err(self.error(e, raw))
+                }
+            };
+        }
+        if let Some(raw) = word.strip_suffix(['x', 'X'])
+            && !raw.is_empty()
+            && raw.bytes().all(|b| b.is_ascii_digit())
+        {
+            let times = raw.parse::<u64>().map_err(|_| {
+                let e = ProgramParsingError::InvalidRepeatCount {
+                    raw: raw.to_owned(),
+                };
+                self.error(e, raw)
+            })?;
+            if !self.code[self.pos..].starts_with('(') {
+                let e = ProgramParsingError::MissingRepeatBody {
+                    raw: word.to_owned(),
+                };
+                return Err(self.error(e, word));
+            }
+            let open = self.pos;
+            self.pos += 1;
+            let body = self.instructions(Some(open))?;
+            return Ok(Instruction::Repeat { times, body });
+        }
+
+        word.parse::<SafeCombo>()
+            .map(Instruction::Turn)
+            .map_err(|e| {
+                let direction_len = word.chars().next().map_or(0, char::len_utf8);
+                let part = match e {
+                    SafeComboParsingError::Empty { .. } => word,
+                    SafeComboParsingError::InvalidDirection { .. } => &word[..direction_len],
+                    SafeComboParsingError::InvalidRotation { .. }
+                    | SafeComboParsingError::RotationOutOfRange { .. } => &word[direction_len..],
+                };
+                self.error(&e, part)
+            })
+    }
+}
+
+/// Combos to turn the dial through, each remembering the 1-based line it
+/// came from so errors can point back at it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Rotations {
+    combos: Vec<SafeCombo>,
+    lines: Vec<usize>,
+}
+
+impl Rotations {
+    /// Each combo alongside its line.
+    pub fn with_lines(&self) -> impl Iterator<Item = (usize, &SafeCombo)> {
+        self.lines.iter().copied().zip(&self.combos)
+    }
+}
+
+/// One combo per line, as in puzzle input.
+impl From<Vec<SafeCombo>> for Rotations {
+    fn from(combos: Vec<SafeCombo>) -> Self {
+        Self {
+            lines: (1..=combos.len()).collect(),
+            combos,
+        }
+    }
+}
+
+impl Deref for Rotations {
+    type Target = [SafeCombo];
+
+    fn deref(&self) -> &Self::Target {
+        &self.combos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::combo::ComboDirection;
+
+    fn turn(direction: ComboDirection, steps: u64) -> SafeCombo {
+        SafeCombo { direction, steps }
+    }
+
+    #[test]
+    fn expands_repeats_and_absolute_moves() {
+        let input = "# scenario\n\n=37 L10  # settle\n2x(R5 1x(L1)) =0\n";
+        let program = Program::parse(input, 100).unwrap();
+        assert_eq!(program.statements.len(), 2);
+        assert_eq!(program.statements[0].line, 3);
+
+        let rotations = program.expand(50);
+        let expected = [
+            (3, turn(ComboDirection::L, 13)),
+            (3, turn(ComboDirection::L, 10)),
+            (4, turn(ComboDirection::R, 5)),
+            (4, turn(ComboDirection::L, 1)),
+            (4, turn(ComboDirection::R, 5)),
+            (4, turn(ComboDirection::L, 1)),
+            (4, turn(ComboDirection::L, 35)),
+        ];
+        let got: Vec<(usize, SafeCombo)> = rotations.with_lines().map(|(l, c)| (l, *c)).collect();
+        assert_eq!(got, expected);
+    }
+
+    #[test]
+    fn points_at_malformed_instructions() {
+        let cases = [
+            ("R5 =100", "invalid absolute position", 4..7),
+            ("3x(L1 R2", "unclosed repeat block", 2..3),
+            ("L1)", "unmatched ')'", 2..3),
+            ("3x L1", "expected '(' after repeat count", 0..2),
+            ("(L1)", "expected a repeat count", 0..1),
+            ("2x(Q1)", "invalid rotation direction", 3..4),
+        ];
+        for (input, message, columns) in cases {
+            let e = Program::parse(input, 100).unwrap_err();
+            assert!(e.message.starts_with(message), "{input}: {}", e.message);
+            assert_eq!(e.columns, columns, "{input}");
+        }
+    }
+}
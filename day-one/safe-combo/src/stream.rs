@@ -0,0 +1,103 @@
+use crate::{
+    DIAL_POSITIONS, START_ROT,
+    combo::SafeCombo,
+    position::Position,
+    program::{Expander, parse_statement},
+};
+use std::{error::Error, io::BufRead};
+
+/// Combos read from a program one line at a time, each with its 1-based
+/// line, holding no more than the line being run. Yields the first read or
+/// parse error and then stops, without reading any further.
+pub struct Stream<R> {
+    reader: R,
+    buf: String,
+    /// Zero-based index of the next line to read.
+    line: usize,
+    expander: Expander,
+    done: bool,
+}
+
+/// Like [`read`](crate::read), but lazily.
+pub fn stream<R: BufRead>(reader: R) -> Stream<R> {
+    Stream {
+        reader,
+        buf: String::new(),
+        line: 0,
+        expander: Expander::new(Position::new(START_ROT, DIAL_POSITIONS)),
+        done: false,
+    }
+}
+
+impl<R: BufRead> Iterator for Stream<R> {
+    type Item = Result<(usize, SafeCombo), Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(combo) = self.expander.next() {
+                return Some(Ok(combo));
+            }
+            if self.done {
+                return None;
+            }
+            self.buf.clear();
+            let statement = match self.reader.read_line(&mut self.buf) {
+                Ok(0) => {
+                    self.done = true;
+                    return None;
+                }
+                Ok(_) => {
+                    let text = self.buf.strip_suffix('\n').unwrap_or(&self.buf);
+                    let text = text.strip_suffix('\r').unwrap_or(text);
+                    self.line += 1;
+                    parse_statement(self.line - 1, text, DIAL_POSITIONS).map_err(Into::into)
+                }
+                Err(e) => Err(e.into()),
+            };
+            match statement {
+                Ok(Some(statement)) => self.expander.load(statement),
+                Ok(None) => {}
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use std::io::{self, BufReader, Read};
+
+    /// Fails the test if anything reads it.
+    struct Untouchable;
+
+    impl Read for Untouchable {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            panic!("read past the first error");
+        }
+    }
+
+    #[test]
+    fn matches_parsing_up_front() {
+        let input = "# warm up\n=37 L10\r\n\n3x(L10 2x(R20)) =0\nR1000\n";
+        let streamed: Vec<(usize, SafeCombo)> =
+            stream(input.as_bytes()).map(Result::unwrap).collect();
+        let parsed = parse(input).unwrap();
+        let parsed: Vec<(usize, SafeCombo)> = parsed.with_lines().map(|(l, c)| (l, *c)).collect();
+        assert_eq!(streamed, parsed);
+    }
+
+    #[test]
+    fn stops_at_the_first_error() {
+        let reader = BufReader::new("L1\nX2\n".as_bytes().chain(Untouchable));
+        let mut combos = stream(reader);
+        assert!(combos.next().unwrap().is_ok());
+        let e = combos.next().unwrap().unwrap_err();
+        assert!(e.to_string().contains("--> <input>:2:1"), "{e}");
+        assert!(combos.next().is_none());
+    }
+}
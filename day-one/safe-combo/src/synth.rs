@@ -0,0 +1,176 @@
+use crate::{
+    combo::{ComboDirection, SafeCombo},
+    dial::ZeroPolicy,
+    position::Position,
+};
+use std::{error::Error, fmt};
+
+/// What a synthesized sequence of combos must do to the dial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Target {
+    pub positions: u64,
+    pub start: u64,
+    pub end: u64,
+    /// How often the dial reaches zero under `policy`.
+    pub password: u64,
+    pub policy: ZeroPolicy,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SynthesisError {
+    EndOffDial {
+        end: u64,
+        positions: u64,
+    },
+    /// Only ending on zero without ever reaching it, from somewhere else.
+    Unreachable,
+    TooManyMoves {
+        needed: u128,
+        max_moves: usize,
+    },
+}
+
+impl fmt::Display for SynthesisError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SynthesisError::EndOffDial { end, positions } => {
+                write!(
+                    f,
+                    "end position {} is off a dial of {} positions",
+                    end, positions
+                )
+            }
+            SynthesisError::Unreachable => {
+                write!(f, "no combos end on zero without reaching zero")
+            }
+            SynthesisError::TooManyMoves { needed, max_moves } => {
+                write!(
+                    f,
+                    "needs {} moves, more than the {} allowed",
+                    needed, max_moves
+                )
+            }
+        }
+    }
+}
+
+impl Error for SynthesisError {}
+
+/// The fewest combos that take a dial from `target.start` to `target.end`
+/// reaching zero exactly `target.password` times, if that is no more than
+/// `max_moves`.
+pub fn synthesize(target: &Target, max_moves: usize) -> Result<Vec<SafeCombo>, SynthesisError> {
+    let Target {
+        positions,
+        end,
+        password,
+        policy,
+        ..
+    } = *target;
+    assert!(positions > 0, "a dial needs at least one position");
+    if end >= positions {
+        return Err(SynthesisError::EndOffDial { end, positions });
+    }
+    let start = target.start % positions;
+
+    // Counted before any combos are built, since a large password can
+    // need more of them than fit in memory.
+    let fits = |needed: u128| {
+        if needed <= max_moves as u128 {
+            Ok(())
+        } else {
+            Err(SynthesisError::TooManyMoves { needed, max_moves })
+        }
+    };
+    match policy {
+        ZeroPolicy::LandsOn => lands_on(positions, start, end, password, fits),
+        ZeroPolicy::PassesThrough => passes_through(positions, start, end, password, fits),
+    }
+}
+
+// Only where each combo stops matters, so the dial takes the short way to
+// zero, spins a full turn for every further landing, then heads to `end`.
+fn lands_on(
+    positions: u64,
+    start: u64,
+    end: u64,
+    password: u64,
+    fits: impl Fn(u128) -> Result<(), SynthesisError>,
+) -> Result<Vec<SafeCombo>, SynthesisError> {
+    if password == 0 {
+        return match (start == end, end == 0) {
+            (true, _) => Ok(Vec::new()),
+            (false, true) => Err(SynthesisError::Unreachable),
+            (false, false) => {
+                fits(1)?;
+                Ok(vec![shortest(positions, start, end)])
+            }
+        };
+    }
+    fits(u128::from(password) + u128::from(end != 0))?;
+    let mut combos = Vec::new();
+    let full_turn = SafeCombo {
+        direction: ComboDirection::R,
+        steps: positions,
+    };
+    combos.push(if start == 0 {
+        full_turn
+    } else {
+        shortest(positions, start, 0)
+    });
+    for _ in 1..password {
+        combos.push(full_turn);
+    }
+    if end != 0 {
+        combos.push(shortest(positions, 0, end));
+    }
+    Ok(combos)
+}
+
+fn shortest(positions: u64, from: u64, to: u64) -> SafeCombo {
+    Position::new(from, positions).toward(to)
+}
+
+// Turning right `k` clicks from `start` reaches zero `(start + k) / positions`
+// times, and consecutive right turns add up, so one turn of
+// `password * positions + end - start` clicks does it, split into as few
+// `u64` sized turns as it takes. Left turns are right turns on the mirrored
+// dial; whichever direction needs fewer clicks wins.
+fn passes_through(
+    positions: u64,
+    start: u64,
+    end: u64,
+    password: u64,
+    fits: impl Fn(u128) -> Result<(), SynthesisError>,
+) -> Result<Vec<SafeCombo>, SynthesisError> {
+    let mirror = |p: u64| (positions - p) % positions;
+    let clicks = |start: u64, end: u64| {
+        (u128::from(password) * u128::from(positions) + u128::from(end))
+            .checked_sub(u128::from(start))
+    };
+    let (direction, clicks) = [
+        (ComboDirection::R, clicks(start, end)),
+        (ComboDirection::L, clicks(mirror(start), mirror(end))),
+    ]
+    .into_iter()
+    .filter_map(|(direction, clicks)| Some((direction, clicks?)))
+    .min_by_key(|&(_, clicks)| clicks)
+    .ok_or(SynthesisError::Unreachable)?;
+
+    let max = u128::from(u64::MAX);
+    fits(clicks.div_ceil(max))?;
+    let mut combos = vec![
+        SafeCombo {
+            direction,
+            steps: u64::MAX,
+        };
+        (clicks / max) as usize
+    ];
+    if clicks % max != 0 {
+        combos.push(SafeCombo {
+            direction,
+            steps: (clicks % max) as u64,
+        });
+    }
+    Ok(combos)
+}
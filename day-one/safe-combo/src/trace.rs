@@ -0,0 +1,175 @@
+use crate::{
+    combo::{ComboDirection, SafeCombo},
+    position::Position,
+};
+use std::{
+    error::Error,
+    fmt,
+    io::{self, Write},
+    str::FromStr,
+};
+
+/// What one combo did to the dial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStep {
+    /// 1-based, as in [`PasswordOverflow`](crate::PasswordOverflow).
+    pub line: usize,
+    pub combo: SafeCombo,
+    pub before: u64,
+    pub after: u64,
+    pub full_turns: u64,
+    /// What the dial counted for this combo under its policy.
+    pub zeros: u64,
+    pub zero_clicks: ZeroClicks,
+}
+
+/// The clicks of a rotation that land on zero, counted from 1. They are
+/// found by walking the dial rather than by `Position::zeros_passed`, so
+/// the two can be checked against each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroClicks {
+    first: u64,
+    every: u64,
+    count: u64,
+}
+
+impl ZeroClicks {
+    pub fn new(before: Position, combo: &SafeCombo) -> Self {
+        let (value, positions) = (before.value(), before.positions());
+        let first = match combo.direction {
+            ComboDirection::L if value == 0 => positions,
+            ComboDirection::L => value,
+            ComboDirection::R => positions - value,
+        };
+        let count = match combo.steps.checked_sub(first) {
+            Some(rest) => rest / positions + 1,
+            None => 0,
+        };
+        Self {
+            first,
+            every: positions,
+            count,
+        }
+    }
+
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u64> {
+        let Self {
+            first,
+            every,
+            count,
+        } = *self;
+        (0..count).map(move |k| first + k * every)
+    }
+}
+
+// Long runs are elided to their first two and last clicks, since a single
+// rotation can pass zero quintillions of times.
+impl fmt::Display for ZeroClicks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let clicks: Vec<String> = if self.count <= 4 {
+            self.iter().map(|click| click.to_string()).collect()
+        } else {
+            let last = self.first + (self.count - 1) * self.every;
+            let mut head: Vec<String> = self.iter().take(2).map(|c| c.to_string()).collect();
+            head.extend(["...".to_owned(), last.to_string()]);
+            head
+        };
+        write!(f, "{}", clicks.join(";"))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TraceFormat {
+    Table,
+    Csv,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceFormatParsingError {
+    pub raw: String,
+}
+
+impl fmt::Display for TraceFormatParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid trace format, expected table or csv (raw: {:?})",
+            self.raw
+        )
+    }
+}
+
+impl Error for TraceFormatParsingError {}
+
+impl FromStr for TraceFormat {
+    type Err = TraceFormatParsingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "table" => Ok(Self::Table),
+            "csv" => Ok(Self::Csv),
+            _ => Err(TraceFormatParsingError { raw: s.to_owned() }),
+        }
+    }
+}
+
+const HEADER: [&str; 7] = [
+    "line",
+    "combo",
+    "before",
+    "after",
+    "full_turns",
+    "zeros",
+    "zero_clicks",
+];
+
+pub fn write_trace(
+    mut out: impl Write,
+    steps: &[TraceStep],
+    format: TraceFormat,
+) -> io::Result<()> {
+    let rows: Vec<[String; 7]> = steps
+        .iter()
+        .map(|step| {
+            [
+                step.line.to_string(),
+                step.combo.to_string(),
+                step.before.to_string(),
+                step.after.to_string(),
+                step.full_turns.to_string(),
+                step.zeros.to_string(),
+                step.zero_clicks.to_string(),
+            ]
+        })
+        .collect();
+    let header = HEADER.map(str::to_owned);
+
+    match format {
+        TraceFormat::Csv => {
+            for row in std::iter::once(&header).chain(&rows) {
+                writeln!(out, "{}", row.join(","))?;
+            }
+        }
+        TraceFormat::Table => {
+            let mut widths = [0; 7];
+            for row in std::iter::once(&header).chain(&rows) {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.len());
+                }
+            }
+            for row in std::iter::once(&header).chain(&rows) {
+                let cells: Vec<String> = row
+                    .iter()
+                    .zip(widths)
+                    .map(|(cell, width)| format!("{cell:>width$}"))
+                    .collect();
+                writeln!(out, "{}", cells.join("  "))?;
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,257 @@
+use proptest::prelude::*;
+use safe_combo::{
+    ComboDirection, Dial, Lock, PasswordCounter, Position, Rotations, SafeCombo, SynthesisError,
+    Target, ZeroPolicy, calculate_lock_passwords, calculate_password, calculate_password_traced,
+    synthesize,
+};
+
+// Turns the dial one click at a time, returning where it stops and how many
+// rotations end on 0 and how many clicks land on 0.
+fn step_by_step(positions: u64, start: u64, combos: &[SafeCombo]) -> (u64, u64, u64) {
+    let (mut position, mut landed, mut passed) = (start, 0, 0);
+    for combo in combos {
+        for _ in 0..combo.steps {
+            position = match combo.direction {
+                ComboDirection::L => (position + positions - 1) % positions,
+                ComboDirection::R => (position + 1) % positions,
+            };
+            passed += u64::from(position == 0);
+        }
+        landed += u64::from(position == 0);
+    }
+    (position, landed, passed)
+}
+
+fn combo() -> impl Strategy<Value = SafeCombo> {
+    (
+        prop_oneof![Just(ComboDirection::L), Just(ComboDirection::R)],
+        0u64..1000,
+    )
+        .prop_map(|(direction, steps)| SafeCombo { direction, steps })
+}
+
+fn rotations() -> impl Strategy<Value = Rotations> {
+    prop::collection::vec(combo(), 0..30).prop_map(Rotations::from)
+}
+
+proptest! {
+    #[test]
+    fn part_one_matches_stepping_the_dial(combos in rotations()) {
+        let PasswordCounter { total, password } =
+            calculate_password(&combos, ZeroPolicy::LandsOn).unwrap();
+        let (position, landed, _) = step_by_step(100, 50, &combos);
+        prop_assert!((0..100).contains(&total));
+        prop_assert_eq!((total, password), (position, landed));
+    }
+
+    #[test]
+    fn position_stays_on_the_dial(
+        positions in 1u64..120,
+        start in 0u64..500,
+        combos in rotations(),
+    ) {
+        let position = combos
+            .iter()
+            .fold(Position::new(start, positions), |position, combo| position + combo);
+        prop_assert!((0..positions).contains(&position.value()));
+    }
+
+    #[test]
+    fn part_two_matches_stepping_the_dial(combos in rotations()) {
+        let PasswordCounter { total, password } =
+            calculate_password(&combos, ZeroPolicy::PassesThrough).unwrap();
+        let (position, _, passed) = step_by_step(100, 50, &combos);
+        prop_assert_eq!((total, password), (position, passed));
+    }
+
+    #[test]
+    fn any_dial_matches_stepping(
+        positions in 1u64..120,
+        start in 0u64..120,
+        combos in rotations(),
+    ) {
+        let start = start % positions;
+        let (position, landed, passed) = step_by_step(positions, start, &combos);
+        for (policy, zeros) in [(ZeroPolicy::LandsOn, landed), (ZeroPolicy::PassesThrough, passed)] {
+            let dial = combos
+                .iter()
+                .try_fold(Dial::new(positions, start, policy), |dial, combo| dial.checked_add(combo))
+                .unwrap();
+            prop_assert_eq!((dial.position(), dial.zeros()), (position, zeros));
+        }
+    }
+
+    #[test]
+    fn trace_matches_stepping_the_dial(combos in rotations()) {
+        let mut steps = Vec::new();
+        calculate_password_traced(&combos, ZeroPolicy::PassesThrough, |step| steps.push(step))
+            .unwrap();
+        prop_assert_eq!(steps.len(), combos.len());
+        for step in &steps {
+            let mut position = step.before;
+            let mut clicks = Vec::new();
+            for click in 1..=step.combo.steps {
+                position = step_by_step(100, position, &[SafeCombo { steps: 1, ..step.combo }]).0;
+                if position == 0 {
+                    clicks.push(click);
+                }
+            }
+            prop_assert_eq!(position, step.after);
+            prop_assert_eq!(step.zero_clicks.iter().collect::<Vec<_>>(), clicks);
+            prop_assert_eq!(step.zero_clicks.count(), step.zeros);
+            prop_assert_eq!(step.full_turns, step.combo.steps / 100);
+        }
+    }
+}
+
+#[test]
+fn left_turn_past_zero_wraps() {
+    let combo = SafeCombo {
+        direction: ComboDirection::L,
+        steps: 60,
+    };
+    assert_eq!((Position::new(50, 100) + &combo).value(), 90);
+}
+
+#[test]
+fn huge_dial_wraps_without_overflow() {
+    let combo = SafeCombo {
+        direction: ComboDirection::R,
+        steps: u64::MAX - 1,
+    };
+    let position = Position::new(u64::MAX - 2, u64::MAX) + &combo;
+    assert_eq!(position.value(), u64::MAX - 3);
+    assert_eq!(
+        Position::new(u64::MAX - 2, u64::MAX).zeros_passed(&combo),
+        1
+    );
+}
+
+proptest! {
+    #[test]
+    fn synthesized_combos_hit_the_target(
+        positions in 1u64..120,
+        start in 0u64..120,
+        end in 0u64..120,
+        password in 0u64..20,
+        passes_through in any::<bool>(),
+    ) {
+        let (start, end) = (start % positions, end % positions);
+        let policy = if passes_through { ZeroPolicy::PassesThrough } else { ZeroPolicy::LandsOn };
+        let target = Target { positions, start, end, password, policy };
+        match synthesize(&target, usize::MAX) {
+            Ok(combos) => {
+                let dial = combos
+                    .iter()
+                    .try_fold(Dial::new(positions, start, policy), |dial, combo| dial.checked_add(combo))
+                    .unwrap();
+                prop_assert_eq!((dial.position(), dial.zeros()), (end, password));
+                let fewest = match policy {
+                    ZeroPolicy::LandsOn if password == 0 => usize::from(start != end),
+                    ZeroPolicy::LandsOn => password as usize + usize::from(end != 0),
+                    ZeroPolicy::PassesThrough => usize::from(start != end || password != 0),
+                };
+                prop_assert_eq!(combos.len(), fewest);
+                if fewest > 0 {
+                    prop_assert!(synthesize(&target, fewest - 1).is_err());
+                }
+            }
+            Err(e) => {
+                prop_assert_eq!(e, SynthesisError::Unreachable);
+                prop_assert!(password == 0 && end == 0 && start != 0);
+            }
+        }
+    }
+}
+
+#[test]
+fn huge_passwords_split_into_u64_turns() {
+    let target = Target {
+        positions: 100,
+        start: 50,
+        end: 32,
+        password: u64::MAX,
+        policy: ZeroPolicy::PassesThrough,
+    };
+    let combos = synthesize(&target, 100).unwrap();
+    assert_eq!(combos.len(), 100);
+    let dial = combos
+        .iter()
+        .try_fold(
+            Dial::new(100, 50, ZeroPolicy::PassesThrough),
+            |dial, combo| dial.checked_add(combo),
+        )
+        .unwrap();
+    assert_eq!((dial.position(), dial.zeros()), (32, u64::MAX));
+    assert_eq!(
+        synthesize(&target, 99),
+        Err(SynthesisError::TooManyMoves {
+            needed: 100,
+            max_moves: 99
+        })
+    );
+}
+
+// Turns a lock of wheels of `sizes`, all starting at 0, one click at a
+// time: each click moves the next wheel only once the wheel before it has
+// gone a full revolution in that direction since it last changed direction.
+// Returns each wheel's position and how many clicks land it on 0.
+fn step_lock(sizes: &[u64], combos: &[SafeCombo]) -> Vec<(u64, u64)> {
+    let mut wheels = vec![(0, 0); sizes.len()];
+    let mut pins: Vec<(Option<ComboDirection>, u64)> = vec![(None, 0); sizes.len()];
+    for combo in combos {
+        for _ in 0..combo.steps {
+            for (i, &size) in sizes.iter().enumerate() {
+                let (position, zeros) = &mut wheels[i];
+                *position = step_by_step(size, *position, &[SafeCombo { steps: 1, ..*combo }]).0;
+                *zeros += u64::from(*position == 0);
+                let (direction, travelled) = &mut pins[i];
+                if *direction != Some(combo.direction) {
+                    *direction = Some(combo.direction);
+                    *travelled = 0;
+                }
+                *travelled += 1;
+                if *travelled <= size {
+                    break;
+                }
+            }
+        }
+    }
+    wheels
+}
+
+proptest! {
+    #[test]
+    fn lock_matches_stepping_the_wheels(
+        sizes in prop::collection::vec(1u64..12, 1..4),
+        combos in rotations(),
+    ) {
+        let lock = Lock::new(sizes.iter().map(|&size| Dial::new(size, 0, ZeroPolicy::PassesThrough)));
+        let counters = calculate_lock_passwords(&combos, lock).unwrap();
+        let wheels: Vec<(u64, u64)> = counters.iter().map(|c| (c.total, c.password)).collect();
+        prop_assert_eq!(wheels, step_lock(&sizes, &combos));
+    }
+
+    #[test]
+    fn one_wheel_lock_is_a_dial(combos in rotations()) {
+        for policy in [ZeroPolicy::LandsOn, ZeroPolicy::PassesThrough] {
+            let lock = Lock::new([Dial::new(100, 50, policy)]);
+            prop_assert_eq!(
+                calculate_lock_passwords(&combos, lock).unwrap(),
+                vec![calculate_password(&combos, policy).unwrap()]
+            );
+        }
+    }
+}
+
+#[test]
+fn reversing_releases_the_next_wheel() {
+    let lock = Lock::new([
+        Dial::new(10, 0, ZeroPolicy::LandsOn),
+        Dial::new(10, 0, ZeroPolicy::LandsOn),
+    ]);
+    let combos = safe_combo::parse("R13 L4 R3").unwrap();
+    let counters = calculate_lock_passwords(&combos, lock).unwrap();
+    let positions: Vec<u64> = counters.iter().map(|c| c.total).collect();
+    assert_eq!(positions, [2, 3]);
+}
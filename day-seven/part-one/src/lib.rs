@@ -0,0 +1,141 @@
+use aoc_common::{ParseError, read_input};
+use std::{collections::HashSet, collections::VecDeque, error::Error, io::BufRead};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpaceType {
+    Empty,
+    Beam,
+    Splitter,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub struct CartesianCoordinate {
+    pub x: usize,
+    pub y: usize,
+}
+
+pub type SpaceMeta = (CartesianCoordinate, SpaceType);
+pub type ProblemMeta = (Vec<SpaceMeta>, Vec<SpaceMeta>, usize, usize);
+
+pub fn calculate_total_beams(
+    beams: &[SpaceMeta],
+    splitters: &[SpaceMeta],
+    width: usize,
+    height: usize,
+) -> u32 {
+    let mut queue: VecDeque<CartesianCoordinate> = beams.iter().map(|(coord, _)| *coord).collect();
+
+    let splitter_coords: HashSet<CartesianCoordinate> =
+        splitters.iter().map(|(coord, _)| *coord).collect();
+
+    let mut seen: HashSet<CartesianCoordinate> = HashSet::new();
+
+    let mut total = 0;
+
+    while let Some(coord) = queue.pop_front() {
+        let next_row = coord.x + 1;
+        if next_row >= height {
+            continue;
+        }
+
+        let next = CartesianCoordinate {
+            x: next_row,
+            y: coord.y,
+        };
+
+        if !seen.insert(next) {
+            continue;
+        }
+
+        if splitter_coords.contains(&next) {
+            total += 1;
+            if coord.y > 0 {
+                queue.push_back(CartesianCoordinate {
+                    x: next.x,
+                    y: next.y - 1,
+                });
+            }
+
+            if coord.y < width - 1 {
+                queue.push_back(CartesianCoordinate {
+                    x: next.x,
+                    y: next.y + 1,
+                });
+            }
+        } else {
+            queue.push_back(next);
+        }
+    }
+    total
+}
+
+fn char_to_space_type(c: &char) -> Option<SpaceType> {
+    match c {
+        '.' => Some(SpaceType::Empty),
+        'S' | '|' => Some(SpaceType::Beam),
+        '^' => Some(SpaceType::Splitter),
+        _ => None,
+    }
+}
+
+pub fn parse(input: &str) -> Result<ProblemMeta, ParseError> {
+    let lines: Vec<&str> = input.lines().collect();
+
+    let height = lines.len();
+    let width = lines.first().map(|s| s.len()).unwrap_or(0);
+
+    for (i, line) in lines.iter().enumerate() {
+        if let Some((j, c)) = line
+            .char_indices()
+            .find(|(_, c)| char_to_space_type(c).is_none())
+        {
+            return Err(ParseError::new(
+                format!("Invalid manifold cell (raw: {:?})", c),
+                i,
+                line,
+                j..j + c.len_utf8(),
+            ));
+        }
+        if line.len() != width {
+            return Err(ParseError::whole_line(
+                format!("Expected a row of width {}, found {}", width, line.len()),
+                i,
+                line,
+            ));
+        }
+    }
+
+    let meta_data: Vec<SpaceMeta> = lines
+        .iter()
+        .enumerate()
+        .flat_map(|(line_idx, line)| {
+            line.chars().enumerate().map(move |(char_idx, char)| {
+                (
+                    CartesianCoordinate {
+                        x: line_idx,
+                        y: char_idx,
+                    },
+                    char_to_space_type(&char).unwrap_or(SpaceType::Empty),
+                )
+            })
+        })
+        .collect();
+
+    let beams: Vec<SpaceMeta> = meta_data
+        .iter()
+        .copied()
+        .filter(|(_, space_type)| *space_type == SpaceType::Beam)
+        .collect();
+
+    let splitters: Vec<SpaceMeta> = meta_data
+        .iter()
+        .copied()
+        .filter(|(_, space_type)| *space_type == SpaceType::Splitter)
+        .collect();
+
+    Ok((beams, splitters, width, height))
+}
+
+pub fn read(reader: impl BufRead) -> Result<ProblemMeta, Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
@@ -1,133 +1,12 @@
-use std::{
-    collections::HashSet,
-    collections::VecDeque,
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SpaceType {
-    Empty,
-    Beam,
-    Splitter,
-}
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct CartesianCoordinate {
-    pub x: usize,
-    pub y: usize,
-}
-
-type SpaceMeta = (CartesianCoordinate, SpaceType);
-type ProblemMeta = (Vec<SpaceMeta>, Vec<SpaceMeta>, usize, usize);
-
-fn calculate_total_beams(
-    beams: Vec<SpaceMeta>,
-    splitters: Vec<SpaceMeta>,
-    width: usize,
-    height: usize,
-) -> u32 {
-    let mut queue: VecDeque<CartesianCoordinate> =
-        beams.into_iter().map(|(coord, _)| coord).collect();
-
-    let splitter_coords: HashSet<CartesianCoordinate> =
-        splitters.into_iter().map(|(coord, _)| coord).collect();
-
-    let mut seen: HashSet<CartesianCoordinate> = HashSet::new();
-
-    let mut total = 0;
-
-    while let Some(coord) = queue.pop_front() {
-        let next_row = coord.x + 1;
-        if next_row >= height {
-            continue;
-        }
-
-        let next = CartesianCoordinate {
-            x: next_row,
-            y: coord.y,
-        };
-
-        if !seen.insert(next) {
-            continue;
-        }
-
-        if splitter_coords.contains(&next) {
-            total += 1;
-            if coord.y > 0 {
-                queue.push_back(CartesianCoordinate {
-                    x: next.x,
-                    y: next.y - 1,
-                });
-            }
-
-            if coord.y < width - 1 {
-                queue.push_back(CartesianCoordinate {
-                    x: next.x,
-                    y: next.y + 1,
-                });
-            }
-        } else {
-            queue.push_back(next);
-        }
-    }
-    total
-}
-
-fn char_to_space_type(c: &char) -> SpaceType {
-    match c {
-        '.' => SpaceType::Empty,
-        'S' | '|' => SpaceType::Beam,
-        '^' => SpaceType::Splitter,
-        _ => SpaceType::Empty,
-    }
-}
-
-fn read_file(file_path: &str) -> Result<ProblemMeta, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<_>, _>>()?;
-
-    let height = lines.len();
-    let width = lines.first().map(|s| s.len()).unwrap_or(0);
-
-    let meta_data: Vec<SpaceMeta> = lines
-        .iter()
-        .enumerate()
-        .flat_map(|(line_idx, line)| {
-            line.chars().enumerate().map(move |(char_idx, char)| {
-                (
-                    CartesianCoordinate {
-                        x: line_idx,
-                        y: char_idx,
-                    },
-                    char_to_space_type(&char),
-                )
-            })
-        })
-        .collect();
-
-    let beams: Vec<SpaceMeta> = meta_data
-        .iter()
-        .copied()
-        .filter(|(_, space_type)| *space_type == SpaceType::Beam)
-        .collect();
-
-    let splitters: Vec<SpaceMeta> = meta_data
-        .iter()
-        .copied()
-        .filter(|(_, space_type)| *space_type == SpaceType::Splitter)
-        .collect();
-
-    Ok((beams, splitters, width, height))
-}
+use aoc_common::InputSource;
+use aoc7pt1::{ProblemMeta, calculate_total_beams, read};
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc7pt1 <input-file>");
-    let (beams, splitters, width, height): ProblemMeta = read_file(&path)?;
-    let total = calculate_total_beams(beams, splitters, width, height);
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc7pt1 <input-file | - | --input-str <input>>");
+    let (beams, splitters, width, height): ProblemMeta = source.read(read)?;
+    let total = calculate_total_beams(&beams, &splitters, width, height);
     println!("final = {total}");
     Ok(())
 }
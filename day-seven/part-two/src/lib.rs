@@ -0,0 +1,54 @@
+use aoc7pt1::{CartesianCoordinate, SpaceMeta};
+use std::collections::HashSet;
+
+pub fn calculate_total_beams(
+    beams: &[SpaceMeta],
+    splitters: &[SpaceMeta],
+    width: usize,
+    height: usize,
+) -> u64 {
+    let splitter_coords: HashSet<CartesianCoordinate> =
+        splitters.iter().map(|(coord, _)| *coord).collect();
+
+    let mut counts = vec![vec![0_u64; width]; height];
+
+    for (coord, _) in beams {
+        counts[coord.x][coord.y] += 1;
+    }
+
+    let mut total = 0;
+
+    for r in 0..height {
+        for c in 0..width {
+            let count = counts[r][c];
+            if count == 0 {
+                continue;
+            }
+
+            let next_row = r + 1;
+            if next_row >= height {
+                total += count;
+                continue;
+            }
+
+            let down = CartesianCoordinate { x: next_row, y: c };
+
+            if splitter_coords.contains(&down) {
+                if c > 0 {
+                    counts[next_row][c - 1] += count;
+                } else {
+                    total += count;
+                }
+
+                if c < width - 1 {
+                    counts[next_row][c + 1] += count;
+                } else {
+                    total += count;
+                }
+            } else {
+                counts[next_row][c] += count;
+            }
+        }
+    }
+    total
+}
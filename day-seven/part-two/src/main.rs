@@ -1,131 +1,13 @@
-use std::{
-    collections::HashSet,
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum SpaceType {
-    Empty,
-    Beam,
-    Splitter,
-}
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-pub struct CartesianCoordinate {
-    pub x: usize,
-    pub y: usize,
-}
-
-type SpaceMeta = (CartesianCoordinate, SpaceType);
-type ProblemMeta = (Vec<SpaceMeta>, Vec<SpaceMeta>, usize, usize);
-
-fn calculate_total_beams(
-    beams: Vec<SpaceMeta>,
-    splitters: Vec<SpaceMeta>,
-    width: usize,
-    height: usize,
-) -> u64 {
-    let splitter_coords: HashSet<CartesianCoordinate> =
-        splitters.into_iter().map(|(coord, _)| coord).collect();
-
-    let mut counts = vec![vec![0_u64; width]; height];
-
-    for (coord, _) in beams {
-        counts[coord.x][coord.y] += 1;
-    }
-
-    let mut total = 0;
-
-    for r in 0..height {
-        for c in 0..width {
-            let count = counts[r][c];
-            if count == 0 {
-                continue;
-            }
-
-            let next_row = r + 1;
-            if next_row >= height {
-                total += count;
-                continue;
-            }
-
-            let down = CartesianCoordinate { x: next_row, y: c };
-
-            if splitter_coords.contains(&down) {
-                if c > 0 {
-                    counts[next_row][c - 1] += count;
-                } else {
-                    total += count;
-                }
-
-                if c < width - 1 {
-                    counts[next_row][c + 1] += count;
-                } else {
-                    total += count;
-                }
-            } else {
-                counts[next_row][c] += count;
-            }
-        }
-    }
-    total
-}
-
-fn char_to_space_type(c: &char) -> SpaceType {
-    match c {
-        '.' => SpaceType::Empty,
-        'S' | '|' => SpaceType::Beam,
-        '^' => SpaceType::Splitter,
-        _ => SpaceType::Empty,
-    }
-}
-
-fn read_file(file_path: &str) -> Result<ProblemMeta, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<_>, _>>()?;
-
-    let height = lines.len();
-    let width = lines.first().map(|s| s.len()).unwrap_or(0);
-
-    let meta_data: Vec<SpaceMeta> = lines
-        .iter()
-        .enumerate()
-        .flat_map(|(line_idx, line)| {
-            line.chars().enumerate().map(move |(char_idx, char)| {
-                (
-                    CartesianCoordinate {
-                        x: line_idx,
-                        y: char_idx,
-                    },
-                    char_to_space_type(&char),
-                )
-            })
-        })
-        .collect();
-
-    let beams: Vec<SpaceMeta> = meta_data
-        .iter()
-        .copied()
-        .filter(|(_, space_type)| *space_type == SpaceType::Beam)
-        .collect();
-
-    let splitters: Vec<SpaceMeta> = meta_data
-        .iter()
-        .copied()
-        .filter(|(_, space_type)| *space_type == SpaceType::Splitter)
-        .collect();
-
-    Ok((beams, splitters, width, height))
-}
+use aoc_common::InputSource;
+use aoc7pt1::{ProblemMeta, read};
+use aoc7pt2::calculate_total_beams;
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc7pt2 <input-file>");
-    let (beams, splitters, width, height): ProblemMeta = read_file(&path)?;
-    let total = calculate_total_beams(beams, splitters, width, height);
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc7pt2 <input-file | - | --input-str <input>>");
+    let (beams, splitters, width, height): ProblemMeta = source.read(read)?;
+    let total = calculate_total_beams(&beams, &splitters, width, height);
     println!("final = {total}");
     Ok(())
 }
@@ -0,0 +1,133 @@
+use aoc_common::{ParseError, read_input};
+use std::{error::Error, io::BufRead};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+pub type Problem = (Vec<u64>, Operation);
+
+pub fn calculate_answer_sum(problems: &[Problem]) -> u64 {
+    problems.iter().fold(0, |acc, problem| {
+        acc + match problem.1 {
+            Operation::Add => problem.0.iter().sum::<u64>(),
+            Operation::Multiply => problem.0.iter().product(),
+        }
+    })
+}
+
+fn make_problems(problem_inputs: &[Vec<u64>], operators: &[Operation]) -> Vec<Problem>
+where
+    Operation: Clone,
+{
+    let problem_count = operators.len();
+    let inputs_by_problem: Vec<Vec<u64>> = (0..problem_count)
+        .map(|c| problem_inputs.iter().map(|v| v[c]).collect::<Vec<u64>>())
+        .collect();
+
+    inputs_by_problem
+        .into_iter()
+        .zip(operators.iter())
+        .map(|(inputs, op)| (inputs, *op))
+        .collect()
+}
+
+pub struct Worksheet {
+    pub operand_rows: Vec<String>,
+    pub operator_row: String,
+}
+
+pub fn read_problems(worksheet: &Worksheet) -> Vec<Problem> {
+    let problem_inputs = worksheet
+        .operand_rows
+        .iter()
+        .map(|l| {
+            l.split_whitespace()
+                .map(|s| s.parse::<u64>().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let operators = worksheet
+        .operator_row
+        .split_whitespace()
+        .map(|o| match o {
+            "+" => Operation::Add,
+            "*" => Operation::Multiply,
+            _ => unreachable!("operators are validated by parse"),
+        })
+        .collect::<Vec<_>>();
+
+    make_problems(&problem_inputs, &operators)
+}
+
+pub fn parse(input: &str) -> Result<Worksheet, ParseError> {
+    let mut lines: Vec<(usize, &str)> = input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .collect();
+    let (operator_line, operator_row) = lines
+        .pop()
+        .ok_or_else(|| ParseError::whole_line("Empty worksheet", 0, ""))?;
+    if lines.is_empty() {
+        return Err(ParseError::whole_line(
+            "Expected operand rows above the operators",
+            operator_line,
+            operator_row,
+        ));
+    }
+    if let Some(o) = operator_row
+        .split_whitespace()
+        .find(|o| !matches!(*o, "+" | "*"))
+    {
+        return Err(ParseError::at(
+            format!("Unknown operator (raw: {:?})", o),
+            operator_line,
+            operator_row,
+            o,
+        ));
+    }
+    let operator_count = operator_row.split_whitespace().count();
+    for &(i, l) in &lines {
+        if let Some((j, c)) = l
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+        {
+            return Err(ParseError::new(
+                format!("Invalid operand character (raw: {:?})", c),
+                i,
+                l,
+                j..j + c.len_utf8(),
+            ));
+        }
+        let mut operands = 0;
+        for operand in l.split_whitespace() {
+            if let Err(e) = operand.parse::<u64>() {
+                return Err(ParseError::at(
+                    format!("Invalid operand: {}", e),
+                    i,
+                    l,
+                    operand,
+                ));
+            }
+            operands += 1;
+        }
+        if operands != operator_count {
+            return Err(ParseError::whole_line(
+                format!("Expected {} operands, found {}", operator_count, operands),
+                i,
+                l,
+            ));
+        }
+    }
+    Ok(Worksheet {
+        operand_rows: lines.into_iter().map(|(_, l)| l.to_owned()).collect(),
+        operator_row: operator_row.to_owned(),
+    })
+}
+
+pub fn read(reader: impl BufRead) -> Result<Worksheet, Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
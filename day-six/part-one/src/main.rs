@@ -32,80 +32,15 @@ Of course, the actual worksheet is much wider. You'll need to make sure to unrol
 Solve the problems on the math worksheet. What is the grand total found by adding together all of the answers to the individual problems?
 */
 
-use std::{
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum Operation {
-    Add,
-    Multiply,
-}
-
-type Problem = (Vec<u64>, Operation);
-
-fn calculate_answer_sum(problems: Vec<Problem>) -> u64 {
-    problems.iter().fold(0, |acc, problem| {
-        acc + match problem.1 {
-            Operation::Add => problem.0.iter().sum::<u64>(),
-            Operation::Multiply => problem.0.iter().product(),
-        }
-    })
-}
-
-fn make_problems(problem_inputs: &[Vec<u64>], operators: &[Operation]) -> Vec<Problem>
-where
-    Operation: Clone,
-{
-    let problem_count = operators.len();
-    let inputs_by_problem: Vec<Vec<u64>> = (0..problem_count)
-        .map(|c| problem_inputs.iter().map(|v| v[c]).collect::<Vec<u64>>())
-        .collect();
-
-    inputs_by_problem
-        .into_iter()
-        .zip(operators.iter())
-        .map(|(inputs, op)| (inputs, *op))
-        .collect()
-}
-
-fn read_file(file_path: &str) -> Result<Vec<Problem>, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<_>, _>>()?;
-    let mut it = lines.clone().into_iter();
-    let problem_inputs = it
-        .by_ref()
-        .take_while(|l| !l.is_empty() && !l.contains("*") && !l.contains("+"))
-        .map(|l| {
-            l.split_whitespace()
-                .filter(|s| !s.trim().is_empty())
-                .map(|s| s.trim().parse::<u64>().unwrap())
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-    let operators = lines
-        .into_iter()
-        .last()
-        .unwrap()
-        .split_whitespace()
-        .map(|o| match o {
-            "+" => Operation::Add,
-            "*" => Operation::Multiply,
-            _ => panic!("unknown operator: {o}"),
-        })
-        .collect::<Vec<_>>();
-
-    Ok(make_problems(&problem_inputs, &operators))
-}
+use aoc_common::InputSource;
+use aoc6pt1::{Problem, calculate_answer_sum, read, read_problems};
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc6pt1 <input-file>");
-    let problems: Vec<Problem> = read_file(&path)?;
-    let total = calculate_answer_sum(problems);
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc6pt1 <input-file | - | --input-str <input>>");
+    let problems: Vec<Problem> = read_problems(&source.read(read)?);
+    let total = calculate_answer_sum(&problems);
     println!("final = {total}");
     Ok(())
 }
@@ -0,0 +1,178 @@
+use aoc6pt1::Worksheet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Multiply,
+    Unknown,
+}
+
+impl Operation {
+    fn from_str(s: &str) -> Operation {
+        match s.trim() {
+            "+" => Operation::Add,
+            "*" => Operation::Multiply,
+            _ => Operation::Unknown,
+        }
+    }
+
+    fn parse_from_line(op_line: &[char], start: usize, end: usize) -> Operation {
+        Operation::from_str(&op_line[start..end].iter().collect::<String>())
+    }
+}
+
+struct Operand(u64);
+struct Operands(Vec<Operand>);
+struct Problem(Operands, Operation);
+pub struct Problems(Vec<Problem>);
+struct Matrix2D<T: Clone>(Vec<Vec<T>>);
+
+impl<T: std::fmt::Debug + Clone + PartialEq> Matrix2D<T> {
+    fn transpose(&self) -> Matrix2D<T> {
+        assert!(!self.0.is_empty());
+        let len = self.0[0].len();
+        let rows = self.0.len();
+        Matrix2D::<T>(
+            (0..len)
+                .map(|c| (0..rows).map(|r| self.0[r][c].clone()).collect::<Vec<T>>())
+                .collect(),
+        )
+    }
+
+    fn from_strings(s: &[String]) -> Matrix2D<char> {
+        let num_operands = s.len() - 1;
+        Matrix2D::<char>(
+            s[0..=num_operands]
+                .iter()
+                .map(|l| l.chars().collect())
+                .collect::<Vec<Vec<char>>>(),
+        )
+    }
+
+    fn take_width(
+        &self,
+        width: usize,
+    ) -> std::iter::Take<std::iter::Enumerate<std::slice::Iter<'_, std::vec::Vec<T>>>> {
+        self.0.iter().enumerate().take(width)
+    }
+
+    fn take_column_slice(&self, column: usize, rows: usize) -> &[T] {
+        &self.0[column][0..rows]
+    }
+
+    fn column_contains_only(&self, column: usize, c: T) -> bool {
+        self.take_column_slice(column, self.0[0].len())
+            .iter()
+            .all(|v| *v == c)
+    }
+}
+
+impl Operand {
+    fn parse_from_grid(grid: &Matrix2D<char>, num_rows: usize, col: usize) -> Operand {
+        Operand(
+            grid.take_column_slice(col, num_rows)
+                .iter()
+                .collect::<String>()
+                .trim()
+                .parse::<u64>()
+                .unwrap(),
+        )
+    }
+}
+
+impl Operands {
+    fn parse_from_grid(
+        grid: &Matrix2D<char>,
+        num_rows: usize,
+        start: usize,
+        end: usize,
+    ) -> Operands {
+        Operands(
+            (start..end)
+                .rev()
+                .map(|col| Operand::parse_from_grid(grid, num_rows, col))
+                .collect(),
+        )
+    }
+
+    fn sum(&self) -> u64 {
+        self.0.iter().map(|o| o.0).sum::<u64>()
+    }
+
+    fn product(&self) -> u64 {
+        self.0.iter().map(|o| o.0).product()
+    }
+}
+
+impl Problem {
+    fn answer(&self) -> u64 {
+        match self.1 {
+            Operation::Add => self.0.sum(),
+            Operation::Multiply => self.0.product(),
+            Operation::Unknown => 0,
+        }
+    }
+}
+
+impl Problems {
+    pub fn sum(&self) -> u64 {
+        self.0.iter().map(|p| p.answer()).sum()
+    }
+}
+
+pub fn read_problems(worksheet: &Worksheet) -> Problems {
+    // Rows may have lost their trailing spaces, so pad them all to the
+    // widest to keep the columns lined up.
+    let width = worksheet
+        .operand_rows
+        .iter()
+        .chain([&worksheet.operator_row])
+        .map(|l| l.len())
+        .max()
+        .unwrap_or(0);
+    let lines: Vec<String> = worksheet
+        .operand_rows
+        .iter()
+        .map(|l| format!("{l:<width$}"))
+        .collect();
+    let num_operands = lines.len();
+
+    let grid: Matrix2D<char> = Matrix2D::<char>::from_strings(&lines).transpose();
+
+    let mut segments = Vec::new();
+    let mut in_seg = false;
+    let mut seg_start = 0usize;
+
+    for (col, _) in grid.take_width(width) {
+        let all_space = grid.column_contains_only(col, ' ');
+        let leaving_seg = in_seg && all_space;
+        let entering_seg = !in_seg && !all_space;
+        if leaving_seg {
+            in_seg = false;
+            segments.push((seg_start, col));
+        }
+        if entering_seg {
+            in_seg = true;
+            seg_start = col;
+        }
+    }
+
+    if in_seg {
+        segments.push((seg_start, width));
+    }
+
+    let operator_row = format!("{:<width$}", worksheet.operator_row)
+        .chars()
+        .collect::<Vec<char>>();
+    Problems(
+        segments
+            .into_iter()
+            .map(|(start, end)| {
+                Problem(
+                    Operands::parse_from_grid(&grid, num_operands, start, end),
+                    Operation::parse_from_line(&operator_row, start, end),
+                )
+            })
+            .collect(),
+    )
+}
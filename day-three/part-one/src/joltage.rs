@@ -10,6 +10,8 @@ pub struct JoltageLayout<const N: usize> {
 #[derive(Debug)]
 pub enum JoltageParsingError {
     EmptyJoltage { raw: String },
+    InvalidBattery { raw: String, index: usize },
+    WrongBankSize { raw: String, expected: usize },
 }
 
 impl fmt::Display for JoltageParsingError {
@@ -18,6 +20,18 @@ impl fmt::Display for JoltageParsingError {
             JoltageParsingError::EmptyJoltage { raw } => {
                 write!(f, "Empty joltage layout (raw: {:?})", raw)
             }
+            JoltageParsingError::InvalidBattery { raw, .. } => {
+                write!(f, "Invalid battery joltage (raw: {:?})", raw)
+            }
+            JoltageParsingError::WrongBankSize { raw, expected } => {
+                write!(
+                    f,
+                    "Expected {} batteries, found {} (raw: {:?})",
+                    expected,
+                    raw.len(),
+                    raw
+                )
+            }
         }
     }
 }
@@ -36,6 +50,19 @@ impl<const N: usize> FromStr for JoltageLayout<N> {
             });
         }
 
+        if let Some((index, c)) = raw.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+            return Err(JoltageParsingError::InvalidBattery {
+                raw: c.to_string(),
+                index,
+            });
+        }
+        if raw.len() != N {
+            return Err(JoltageParsingError::WrongBankSize {
+                raw: raw.to_string(),
+                expected: N,
+            });
+        }
+
         let batteries_slice: Vec<u8> = raw.as_bytes().iter().map(|b| b - b'0').collect();
         let mut batteries = [0u8; N];
         batteries.copy_from_slice(batteries_slice.as_slice());
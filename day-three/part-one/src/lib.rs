@@ -0,0 +1,53 @@
+pub mod joltage;
+use aoc_common::{ParseError, read_input};
+use joltage::{JoltageLayout, JoltageParsingError};
+use std::{cmp::Reverse, error::Error, io::BufRead};
+
+fn get_biggest_battery(battery_slice: &[u8]) -> (usize, &u8) {
+    battery_slice
+        .iter()
+        .enumerate()
+        .max_by(|(a_i, a), (b_i, b)| (a, Reverse(*a_i)).cmp(&(b, Reverse(*b_i))))
+        .unwrap_or((0, &0))
+}
+
+pub fn calculate_joltage<const N: usize>(joltage: JoltageLayout<N>) -> u64 {
+    let (highest_num_id, highest_num) = get_biggest_battery(&joltage.batteries);
+
+    if highest_num_id == N - 1 {
+        let slice = &joltage.batteries[0..highest_num_id];
+        let (_, next_highest_num) = get_biggest_battery(slice);
+        (next_highest_num * 10 + highest_num).into()
+    } else {
+        let slice = &joltage.batteries[highest_num_id + 1..];
+        let (_, next_highest_num) = get_biggest_battery(slice);
+        (highest_num * 10 + next_highest_num).into()
+    }
+}
+
+pub fn calculate_joltage_sum<const N: usize>(joltages: &[JoltageLayout<N>]) -> u64 {
+    joltages.iter().map(|j| calculate_joltage(*j)).sum::<u64>()
+}
+
+pub fn parse<const N: usize>(input: &str) -> Result<Vec<JoltageLayout<N>>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse::<JoltageLayout<N>>().map_err(|e| {
+                let bank = line.trim();
+                let part = match &e {
+                    JoltageParsingError::InvalidBattery { raw, index } => {
+                        &bank[*index..*index + raw.len()]
+                    }
+                    _ => bank,
+                };
+                ParseError::at(&e, i, line, part)
+            })
+        })
+        .collect()
+}
+
+pub fn read<const N: usize>(reader: impl BufRead) -> Result<Vec<JoltageLayout<N>>, Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
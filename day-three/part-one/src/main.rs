@@ -30,57 +30,15 @@ The total output joltage is the sum of the maximum joltage from each bank, so in
 There are many batteries in front of you. Find the maximum joltage possible from each bank; what is the total output joltage?
 */
 
-mod joltage;
-use joltage::{JoltageLayout, JoltageParsingError};
-use std::{
-    cmp::Reverse,
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-fn get_biggest_battery(battery_slice: &[u8]) -> (usize, &u8) {
-    battery_slice
-        .iter()
-        .enumerate()
-        .max_by(|(a_i, a), (b_i, b)| (a, Reverse(*a_i)).cmp(&(b, Reverse(*b_i))))
-        .unwrap_or((0, &0))
-}
-
-fn calculate_joltage<const N: usize>(joltage: JoltageLayout<N>) -> u64 {
-    let (highest_num_id, highest_num) = get_biggest_battery(&joltage.batteries);
-
-    if highest_num_id == N - 1 {
-        let slice = &joltage.batteries[0..highest_num_id];
-        let (_, next_highest_num) = get_biggest_battery(slice);
-        (next_highest_num * 10 + highest_num).into()
-    } else {
-        let slice = &joltage.batteries[highest_num_id + 1..];
-        let (_, next_highest_num) = get_biggest_battery(slice);
-        (highest_num * 10 + next_highest_num).into()
-    }
-}
-
-fn calculate_joltage_sum<const N: usize>(joltages: Vec<JoltageLayout<N>>) -> u64 {
-    joltages.iter().map(|j| calculate_joltage(*j)).sum::<u64>()
-}
-
-fn read_file<const N: usize>(file_path: &str) -> Result<Vec<JoltageLayout<N>>, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<String>, _>>()?;
-    lines
-        .iter()
-        .map(|l| l.to_string().parse::<JoltageLayout<N>>())
-        .collect::<Result<Vec<JoltageLayout<N>>, _>>()
-        .map_err(|e: JoltageParsingError| -> Box<dyn Error> { Box::new(e) })
-}
+use aoc_common::InputSource;
+use aoc3pt1::{calculate_joltage_sum, joltage::JoltageLayout, read};
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc3pt1 <input-file>");
-    let products: Vec<JoltageLayout<100>> = read_file(&path)?;
-    let total = calculate_joltage_sum(products);
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc3pt1 <input-file | - | --input-str <input>>");
+    let products: Vec<JoltageLayout<100>> = source.read(read)?;
+    let total = calculate_joltage_sum(&products);
     println!("final = {total}");
     Ok(())
 }
@@ -0,0 +1,26 @@
+use aoc3pt1::joltage::JoltageLayout;
+
+pub fn calculate_joltage<const N: usize, const M: usize>(joltage: JoltageLayout<N>) -> u64 {
+    assert!(M > 0 && M <= N);
+
+    let mut to_drop = N - M;
+    let mut stack = Vec::with_capacity(M);
+    joltage.batteries.iter().for_each(|b| {
+        while to_drop > 0 && stack.last().is_some_and(|last| *last < *b) {
+            stack.pop();
+            to_drop -= 1;
+        }
+        stack.push(*b);
+    });
+    stack.truncate(M);
+    let mut out = [0u8; M];
+    out.copy_from_slice(&stack[..M]);
+    out.iter().fold(0, |acc, b| acc * 10 + *b as u64)
+}
+
+pub fn calculate_joltage_sum<const N: usize>(joltages: &[JoltageLayout<N>]) -> u64 {
+    joltages
+        .iter()
+        .map(|j| calculate_joltage::<N, 12>(*j))
+        .sum::<u64>()
+}
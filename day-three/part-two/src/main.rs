@@ -24,55 +24,16 @@ Now, the joltages are much larger:
 The total output joltage is now much larger: 987654321111 + 811111111119 + 434234234278 + 888911112111 = 3121910778619./
 */
 
-mod joltage;
-use joltage::{JoltageLayout, JoltageParsingError};
-use std::{
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-fn calculate_joltage<const N: usize, const M: usize>(joltage: JoltageLayout<N>) -> u64 {
-    assert!(M > 0 && M <= N);
-
-    let mut to_drop = N - M;
-    let mut stack = Vec::with_capacity(M);
-    joltage.batteries.iter().for_each(|b| {
-        while to_drop > 0 && stack.last().is_some_and(|last| *last < *b) {
-            stack.pop();
-            to_drop -= 1;
-        }
-        stack.push(*b);
-    });
-    stack.truncate(M);
-    let mut out = [0u8; M];
-    out.copy_from_slice(&stack[..M]);
-    out.iter().fold(0, |acc, b| acc * 10 + *b as u64)
-}
-
-fn calculate_joltage_sum<const N: usize>(joltages: Vec<JoltageLayout<N>>) -> u64 {
-    joltages
-        .iter()
-        .map(|j| calculate_joltage::<N, 12>(*j))
-        .sum::<u64>()
-}
-
-fn read_file<const N: usize>(file_path: &str) -> Result<Vec<JoltageLayout<N>>, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<String>, _>>()?;
-    lines
-        .iter()
-        .map(|l| l.to_string().parse::<JoltageLayout<N>>())
-        .collect::<Result<Vec<JoltageLayout<N>>, _>>()
-        .map_err(|e: JoltageParsingError| -> Box<dyn Error> { Box::new(e) })
-}
+use aoc_common::InputSource;
+use aoc3pt1::{joltage::JoltageLayout, read};
+use aoc3pt2::calculate_joltage_sum;
+use std::{env, error::Error};
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc3pt2 <input-file>");
-    let products: Vec<JoltageLayout<100>> = read_file(&path)?;
-    let total = calculate_joltage_sum(products);
+    let source = InputSource::from_args(env::args().skip(1))
+        .expect("usage: aoc3pt2 <input-file | - | --input-str <input>>");
+    let products: Vec<JoltageLayout<100>> = source.read(read)?;
+    let total = calculate_joltage_sum(&products);
     println!("final = {total}");
     Ok(())
 }
@@ -0,0 +1,37 @@
+use aoc3pt1::joltage::JoltageLayout;
+use proptest::prelude::*;
+
+const N: usize = 12;
+
+// Tries every way of keeping `m` batteries in order.
+fn best_subsequence(batteries: &[u8], m: usize) -> u64 {
+    (0u32..1 << batteries.len())
+        .filter(|mask| mask.count_ones() as usize == m)
+        .map(|mask| {
+            batteries
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << i) != 0)
+                .fold(0, |acc, (_, b)| acc * 10 + u64::from(*b))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+fn layout() -> impl Strategy<Value = JoltageLayout<N>> {
+    prop::array::uniform12(1u8..=9).prop_map(|batteries| JoltageLayout { batteries })
+}
+
+proptest! {
+    #[test]
+    fn part_one_matches_exhaustive_search(layout in layout()) {
+        prop_assert_eq!(aoc3pt1::calculate_joltage(layout), best_subsequence(&layout.batteries, 2));
+    }
+
+    #[test]
+    fn part_two_matches_exhaustive_search(layout in layout()) {
+        prop_assert_eq!(aoc3pt2::calculate_joltage::<N, 1>(layout), best_subsequence(&layout.batteries, 1));
+        prop_assert_eq!(aoc3pt2::calculate_joltage::<N, 5>(layout), best_subsequence(&layout.batteries, 5));
+        prop_assert_eq!(aoc3pt2::calculate_joltage::<N, 12>(layout), best_subsequence(&layout.batteries, 12));
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    Products,
+    products::{Repeats, RepetitionRule, RepetitionRuleError},
+};
+use aoc_common::InputSource;
+use std::{env, error::Error, iter::Peekable};
+
+/// Takes `--exactly <k>`, `--at-least <k>`, `--at-most <k>`,
+/// `--any-of <k,...>` and `--base <b>` from the front of `args`, stopping at
+/// the input, and builds the rule they ask for out of `default`. The last
+/// repeat flag given wins.
+pub fn rule_from_args(
+    args: &mut Peekable<impl Iterator<Item = String>>,
+    default: RepetitionRule,
+) -> Result<RepetitionRule, Box<dyn Error>> {
+    let (mut repeats, mut base) = (default.repeats().clone(), default.base());
+    while let Some(flag) = args.next_if(|arg| arg.starts_with("--") && arg != "--input-str") {
+        let value = args.next().ok_or(format!("missing value for {flag}"))?;
+        let count = |raw: &str| {
+            raw.trim()
+                .parse::<usize>()
+                .map_err(|_| RepetitionRuleError::InvalidRepeatCount {
+                    raw: raw.to_owned(),
+                })
+        };
+        match flag.as_str() {
+            "--exactly" => repeats = Repeats::Exactly(count(&value)?),
+            "--at-least" => repeats = Repeats::AtLeast(count(&value)?),
+            "--at-most" => repeats = Repeats::AtMost(count(&value)?),
+            "--any-of" => {
+                repeats = Repeats::AnyOf(value.split(',').map(count).collect::<Result<_, _>>()?)
+            }
+            "--base" => {
+                base = value
+                    .parse()
+                    .map_err(|_| RepetitionRuleError::InvalidBase { raw: value })?
+            }
+            _ => return Err(format!("unknown flag {flag}").into()),
+        }
+    }
+    Ok(RepetitionRule::new(repeats, base)?)
+}
+
+/// The whole of a day two binary, finding invalid IDs by `default` unless
+/// flags say otherwise.
+pub fn main(default: RepetitionRule, usage: &str) -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1).peekable();
+    let rule = rule_from_args(&mut args, default)?;
+    let source = InputSource::from_args(args).expect(usage);
+    let total = source.read(Products::read)?.sum_invalid_ids(&rule)?;
+    println!("final = {total}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(args: &[&str]) -> Result<(RepetitionRule, Vec<String>), String> {
+        let mut args = args.iter().map(|a| a.to_string()).peekable();
+        let rule = rule_from_args(&mut args, RepetitionRule::twice()).map_err(|e| e.to_string())?;
+        Ok((rule, args.collect()))
+    }
+
+    #[test]
+    fn takes_flags_up_to_the_input() {
+        let (got, rest) = rule(&["--at-most", "3", "--base", "16", "in.txt"]).unwrap();
+        assert_eq!(got, RepetitionRule::new(Repeats::AtMost(3), 16).unwrap());
+        assert_eq!(rest, ["in.txt"]);
+
+        let (got, _) = rule(&["--any-of", "2,5", "-"]).unwrap();
+        assert_eq!(got.repeats(), &Repeats::AnyOf([2, 5].into()));
+        assert_eq!(got.base(), 10);
+
+        let (got, rest) = rule(&["--input-str", "11-22"]).unwrap();
+        assert_eq!(got, RepetitionRule::twice());
+        assert_eq!(rest, ["--input-str", "11-22"]);
+
+        assert!(
+            rule(&["--base", "37", "-"])
+                .unwrap_err()
+                .contains("Invalid base")
+        );
+        assert!(
+            rule(&["--exactly", "1", "-"])
+                .unwrap_err()
+                .contains("Invalid repeat count")
+        );
+        assert!(rule(&["--any-of", "2,x", "-"]).is_err());
+        assert!(rule(&["--at-least"]).is_err());
+    }
+}
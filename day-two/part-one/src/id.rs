@@ -0,0 +1,201 @@
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{CheckedAdd, CheckedMul, ToPrimitive, Unsigned};
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+/// An unsigned integer product IDs can be held in, such as `u64`, `u128` or
+/// [`BigUint`] for IDs of any length.
+pub trait ProductId:
+    Clone
+    + Ord
+    + fmt::Debug
+    + fmt::Display
+    + FromStr
+    + From<u64>
+    + Unsigned
+    + Integer
+    + CheckedAdd
+    + CheckedMul
+    + ToPrimitive
+{
+    /// The most digits in `base` an ID can have, or `None` if unbounded.
+    fn max_digits(base: u32) -> Option<usize>;
+
+    fn to_big(&self) -> BigUint;
+
+    /// `big` as `Self`, or `None` if it doesn't fit.
+    fn from_big(big: &BigUint) -> Option<Self>;
+}
+
+impl ProductId for u64 {
+    fn max_digits(base: u32) -> Option<usize> {
+        Some(u64::MAX.ilog(base.into()) as usize + 1)
+    }
+
+    fn to_big(&self) -> BigUint {
+        (*self).into()
+    }
+
+    fn from_big(big: &BigUint) -> Option<Self> {
+        big.to_u64()
+    }
+}
+
+impl ProductId for u128 {
+    fn max_digits(base: u32) -> Option<usize> {
+        Some(u128::MAX.ilog(base.into()) as usize + 1)
+    }
+
+    fn to_big(&self) -> BigUint {
+        (*self).into()
+    }
+
+    fn from_big(big: &BigUint) -> Option<Self> {
+        big.to_u128()
+    }
+}
+
+impl ProductId for BigUint {
+    fn max_digits(_: u32) -> Option<usize> {
+        None
+    }
+
+    fn to_big(&self) -> BigUint {
+        self.clone()
+    }
+
+    fn from_big(big: &BigUint) -> Option<Self> {
+        Some(big.clone())
+    }
+}
+
+/// The digits of `n` in `base`, most significant first.
+pub fn digits_of<T: ProductId>(n: &T, base: u32) -> Vec<u32> {
+    let base = T::from(base.into());
+    let mut digits = Vec::new();
+    let mut rest = n.clone();
+    loop {
+        let (quotient, digit) = rest.div_rem(&base);
+        digits.push(digit.to_u32().expect("digit below base"));
+        rest = quotient;
+        if rest.is_zero() {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+pub fn num_digits<T: ProductId>(n: &T, base: u32) -> usize {
+    let base = T::from(base.into());
+    let mut digits = 1;
+    let mut rest = n.clone() / base.clone();
+    while !rest.is_zero() {
+        rest = rest / base.clone();
+        digits += 1;
+    }
+    digits
+}
+
+/// Powers of the base and repeat multipliers for IDs of up to `digits`
+/// digits, generated for the widest ID in use rather than fixed at compile
+/// time.
+#[derive(Debug, Clone)]
+pub struct Tables<T> {
+    base: u32,
+    digits: usize,
+    /// `base^0` to `base^(digits - 1)`; `base^digits` may not fit in `T`.
+    pow: Vec<T>,
+}
+
+impl<T: ProductId> Tables<T> {
+    pub fn new(base: u32, digits: usize) -> Self {
+        assert!((2..=36).contains(&base), "base {base} is not 2 to 36");
+        if let Some(max) = T::max_digits(base) {
+            assert!(
+                digits <= max,
+                "{digits}-digit IDs don't fit in {max} digits"
+            );
+        }
+        let mut pow: Vec<T> = Vec::with_capacity(digits);
+        for k in 0..digits {
+            pow.push(match k {
+                0 => T::one(),
+                _ => pow[k - 1].clone() * T::from(base.into()),
+            });
+        }
+        Self { base, digits, pow }
+    }
+
+    /// Tables covering every ID up to `upper` in `base`.
+    pub fn covering(base: u32, upper: &T) -> Self {
+        Self::new(base, num_digits(upper, base))
+    }
+
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// `base^k`, for `k` below [`digits`](Self::digits).
+    pub fn pow(&self, k: usize) -> &T {
+        &self.pow[k]
+    }
+
+    /// `1 + base^k + base^2k + ... + base^(d - k)`, which times a `k`-digit
+    /// chunk gives the `d`-digit ID repeating it `d / k` times, or `None` if
+    /// it overflows `T`.
+    pub fn rep(&self, d: usize, k: usize) -> Option<T> {
+        (0..d / k).try_fold(T::zero(), |rep, i| rep.checked_add(&self.pow[i * k]))
+    }
+
+    /// The `k`-digit chunks whose `d`-digit repeats fall in
+    /// `lower..=upper`, if any, alongside the [`rep`](Self::rep) multiplier.
+    pub fn chunks(
+        &self,
+        lower: &T,
+        upper: &T,
+        d: usize,
+        k: usize,
+    ) -> Option<(T, RangeInclusive<T>)> {
+        // A multiplier past `T` makes every repeat larger than any `T`, so
+        // none are in range.
+        let rep = self.rep(d, k)?;
+        let first = lower.div_ceil(&rep).max(self.pow[k - 1].clone());
+        let last = (upper.clone() / rep.clone()).min(self.pow[k].clone() - T::one());
+        (first <= last).then_some((rep, first..=last))
+    }
+
+    /// Counts the `d`-digit IDs in `lower..=upper` made of a `k`-digit chunk
+    /// repeated `d / k` times.
+    pub fn count_repeats(&self, lower: &T, upper: &T, d: usize, k: usize) -> T {
+        self.chunks(lower, upper, d, k)
+            .map_or(T::zero(), |(_, chunks)| {
+                let (first, last) = chunks.into_inner();
+                last - first + T::one()
+            })
+    }
+
+    /// Sums the `d`-digit IDs in `lower..=upper` made of a `k`-digit chunk
+    /// repeated `d / k` times, or `None` if the sum overflows `T`.
+    pub fn sum_repeats(&self, lower: &T, upper: &T, d: usize, k: usize) -> Option<T> {
+        let Some((rep, chunks)) = self.chunks(lower, upper, d, k) else {
+            return Some(T::zero());
+        };
+        let (first, last) = chunks.into_inner();
+        let count = last.clone() - first.clone() + T::one();
+        // Of `count` and `first + last`, one is even, so halve that one
+        // rather than their product, which may not fit.
+        let two = T::from(2);
+        let ends = first + last;
+        let chunk_sum = if count.is_even() {
+            (count / two).checked_mul(&ends)?
+        } else {
+            (ends / two).checked_mul(&count)?
+        };
+        rep.checked_mul(&chunk_sum)
+    }
+}
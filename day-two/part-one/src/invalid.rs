@@ -0,0 +1,195 @@
+use crate::{
+    id::{ProductId, Tables, num_digits},
+    products::{ProductInfo, Repeats, RepetitionRule},
+};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::iter::FusedIterator;
+
+// μ(n): 0 if a square divides n, else -1 to the number of its prime factors.
+fn mobius(n: usize) -> i64 {
+    let mut rest = n;
+    let mut sign = 1;
+    let mut p = 2;
+    while p * p <= rest {
+        if rest.is_multiple_of(p) {
+            rest /= p;
+            if rest.is_multiple_of(p) {
+                return 0;
+            }
+            sign = -sign;
+        }
+        p += 1;
+    }
+    if rest > 1 { -sign } else { sign }
+}
+
+// A `d`-digit ID whose shortest chunk repeats `r` times also repeats every
+// `m` dividing `r` times, so summing the IDs repeating each allowed count
+// would count `111111` for both 2 and 3. Weighting the IDs repeating a
+// chunk `m` times by `w(m) = Σ μ(m / j)` over the `j` dividing `m` that an
+// allowed count divides makes the weights seen by each ID add up to one if
+// it is invalid and zero if not. For "at least twice" that is `-μ(m)`, and
+// for "exactly twice" just `m = 2`.
+fn weights(repeats: &Repeats, d: usize) -> impl Iterator<Item = (usize, i64)> {
+    let divides = move |n: usize, m: usize| m.is_multiple_of(n);
+    let covered = move |j: usize| (1..=j).any(|n| divides(n, j) && repeats.allows(n));
+    (1..=d)
+        .filter(move |&m| divides(m, d))
+        .map(move |m| {
+            let w = (1..=m)
+                .filter(|&j| divides(j, m) && covered(j))
+                .map(|j| mobius(m / j))
+                .sum();
+            (m, w)
+        })
+        .filter(|&(_, w)| w != 0)
+}
+
+type Measure<T> = fn(&Tables<T>, &T, &T, usize, usize) -> Option<T>;
+
+fn counted<T: ProductId>(
+    tables: &Tables<T>,
+    lower: &T,
+    upper: &T,
+    d: usize,
+    k: usize,
+) -> Option<T> {
+    Some(tables.count_repeats(lower, upper, d, k))
+}
+
+/// The invalid IDs of a range under a [`RepetitionRule`], in ascending
+/// order, each found by jumping straight to it rather than by scanning the
+/// IDs in between. [`count_remaining`](Self::count_remaining),
+/// [`total`](Self::total), [`nth`](Iterator::nth) and
+/// [`contains`](Self::contains) are answered in closed form, over the IDs
+/// not yet yielded.
+#[derive(Debug, Clone)]
+pub struct InvalidIds<T> {
+    rule: RepetitionRule,
+    tables: Tables<T>,
+    /// The IDs still to search, or `None` once past the end.
+    rest: Option<(T, T)>,
+}
+
+impl<T: ProductId> InvalidIds<T> {
+    pub fn new(product: &ProductInfo<T>, rule: &RepetitionRule) -> Self {
+        let (lower, upper) = (product.lower_id.clone(), product.upper_id.clone());
+        Self {
+            tables: Tables::covering(rule.base(), &upper),
+            rule: rule.clone(),
+            rest: (lower <= upper).then_some((lower, upper)),
+        }
+    }
+
+    /// Totals `measure` over the repeats in `lower..=upper`, by [`weights`],
+    /// or `None` if the total overflows `T`.
+    fn weigh(&self, lower: &T, upper: &T, measure: Measure<T>) -> Option<T> {
+        let base = self.rule.base();
+        // The positive and negative weights are totalled apart, and wider
+        // than `T` so that neither overflows before the other is taken
+        // away. Each repeat measured is an invalid ID, so a measure that
+        // overflows `T` means the total does too.
+        let (mut added, mut taken) = (BigUint::zero(), BigUint::zero());
+        for d in num_digits(lower, base)..=num_digits(upper, base) {
+            for (m, w) in weights(self.rule.repeats(), d) {
+                let total = if w > 0 { &mut added } else { &mut taken };
+                *total +=
+                    measure(&self.tables, lower, upper, d, d / m)?.to_big() * w.unsigned_abs();
+            }
+        }
+        T::from_big(&(added - taken))
+    }
+
+    fn weigh_rest(&self, measure: Measure<T>) -> Option<T> {
+        self.rest
+            .as_ref()
+            .map_or(Some(T::zero()), |(lower, upper)| {
+                self.weigh(lower, upper, measure)
+            })
+    }
+
+    /// How many invalid IDs are left, which may be more than fit in `usize`.
+    pub fn count_remaining(&self) -> T {
+        self.weigh_rest(counted)
+            .expect("no more invalid IDs than IDs")
+    }
+
+    /// The sum of the invalid IDs left, or `None` if it overflows `T`.
+    pub fn total(&self) -> Option<T> {
+        self.weigh_rest(Tables::sum_repeats)
+    }
+
+    /// Whether `id` is one of the invalid IDs left.
+    pub fn contains(&self, id: &T) -> bool {
+        let Some((lower, upper)) = &self.rest else {
+            return false;
+        };
+        let d = num_digits(id, self.rule.base());
+        // `id` repeats a chunk `m` times just when the repeats of `m`
+        // chunks between `id` and itself take in any.
+        lower <= id
+            && id <= upper
+            && self
+                .allowed(d)
+                .any(|m| self.tables.chunks(id, id, d, d / m).is_some())
+    }
+
+    /// The repeat counts the rule allows of `d`-digit IDs.
+    fn allowed(&self, d: usize) -> impl Iterator<Item = usize> + '_ {
+        (2..=d).filter(move |&m| d.is_multiple_of(m) && self.rule.repeats().allows(m))
+    }
+
+    /// Yields `next` and moves past it, or ends the iterator on `None`.
+    fn take(&mut self, next: Option<T>) -> Option<T> {
+        let (_, upper) = self.rest.take()?;
+        let id = next?;
+        if id < upper {
+            self.rest = Some((id.clone() + T::one(), upper));
+        }
+        Some(id)
+    }
+}
+
+impl<T: ProductId> Iterator for InvalidIds<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let (lower, upper) = self.rest.as_ref()?;
+        let base = self.rule.base();
+        // The first repeat of each allowed count at or after `lower`, of
+        // the fewest digits that have any.
+        let next = (num_digits(lower, base)..=num_digits(upper, base)).find_map(|d| {
+            self.allowed(d)
+                .filter_map(|m| self.tables.chunks(lower, upper, d, d / m))
+                .map(|(rep, chunks)| rep * chunks.into_inner().0)
+                .min()
+        });
+        self.take(next)
+    }
+
+    // Binary searches for the first ID with `n + 1` invalid IDs up to it.
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let (lower, upper) = self.rest.as_ref()?;
+        let wanted = T::from(n as u64) + T::one();
+        let count = |to: &T| {
+            self.weigh(lower, to, counted)
+                .expect("no more invalid IDs than IDs")
+        };
+        let next = (count(upper) >= wanted).then(|| {
+            let (mut low, mut high) = (lower.clone(), upper.clone());
+            while low < high {
+                let mid = low.clone() + (high.clone() - low.clone()) / T::from(2);
+                if count(&mid) >= wanted {
+                    high = mid;
+                } else {
+                    low = mid + T::one();
+                }
+            }
+            low
+        });
+        self.take(next)
+    }
+}
+
+impl<T: ProductId> FusedIterator for InvalidIds<T> {}
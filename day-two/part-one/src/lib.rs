@@ -0,0 +1,141 @@
+pub mod cli;
+pub mod id;
+pub mod invalid;
+pub mod products;
+use aoc_common::{ParseError, read_input};
+use id::ProductId;
+use invalid::InvalidIds;
+use num_bigint::BigUint;
+use products::{ProductInfo, ProductParsingError, RepetitionRule};
+use std::{error::Error, fmt, io::BufRead};
+
+/// The invalid IDs in `product`, in order, without scanning the IDs
+/// between them.
+pub fn invalid_ids<T: ProductId>(product: &ProductInfo<T>) -> InvalidIds<T> {
+    product.invalid_ids(&RepetitionRule::twice())
+}
+
+/// The invalid IDs summed past what their ID type holds, on reaching the
+/// product `raw`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidIdSumOverflow {
+    pub raw: String,
+}
+
+impl InvalidIdSumOverflow {
+    fn at<T: ProductId>(product: &ProductInfo<T>) -> Self {
+        Self {
+            raw: format!("{}-{}", product.lower_id, product.upper_id),
+        }
+    }
+}
+
+impl fmt::Display for InvalidIdSumOverflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid ID sum overflows the ID type (raw: {:?})",
+            self.raw
+        )
+    }
+}
+
+impl Error for InvalidIdSumOverflow {}
+
+/// Sums the IDs in `products` made of some chunk of digits repeated twice.
+pub fn calculate_invalid_id_sum<T: ProductId>(
+    products: &[ProductInfo<T>],
+) -> Result<T, InvalidIdSumOverflow> {
+    sum_invalid_ids(products, &RepetitionRule::twice())
+}
+
+/// Sums the IDs in `products` that `rule` finds invalid.
+pub fn sum_invalid_ids<T: ProductId>(
+    products: &[ProductInfo<T>],
+    rule: &RepetitionRule,
+) -> Result<T, InvalidIdSumOverflow> {
+    products.iter().try_fold(T::zero(), |total, p| {
+        p.invalid_ids(rule)
+            .total()
+            .and_then(|sum| total.checked_add(&sum))
+            .ok_or_else(|| InvalidIdSumOverflow::at(p))
+    })
+}
+
+/// Products read at the narrowest width that holds all of their IDs.
+#[derive(Debug, Clone)]
+pub enum Products {
+    U64(Vec<ProductInfo>),
+    U128(Vec<ProductInfo<u128>>),
+    Big(Vec<ProductInfo<BigUint>>),
+}
+
+impl Products {
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        parse(input)
+            .map(Products::U64)
+            .or_else(|_| parse(input).map(Products::U128))
+            .or_else(|_| parse(input).map(Products::Big))
+    }
+
+    pub fn read(reader: impl BufRead) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::parse(&read_input(reader)?)?)
+    }
+
+    /// Like [`sum_invalid_ids`], but widening the IDs as far as the sum
+    /// needs rather than overflowing.
+    pub fn sum_invalid_ids(&self, rule: &RepetitionRule) -> Result<BigUint, InvalidIdSumOverflow> {
+        match self {
+            Products::U64(products) => sum_widening(products, rule),
+            Products::U128(products) => sum_widening(products, rule),
+            Products::Big(products) => sum_invalid_ids(products, rule),
+        }
+    }
+}
+
+fn sum_widening<T: ProductId>(
+    products: &[ProductInfo<T>],
+    rule: &RepetitionRule,
+) -> Result<BigUint, InvalidIdSumOverflow> {
+    sum_invalid_ids(products, rule)
+        .map(|sum| sum.to_big())
+        .or_else(|_| {
+            let products: Vec<ProductInfo<BigUint>> = products
+                .iter()
+                .map(|p| ProductInfo {
+                    lower_id: p.lower_id.to_big(),
+                    upper_id: p.upper_id.to_big(),
+                })
+                .collect();
+            sum_invalid_ids(&products, rule)
+        })
+}
+
+pub fn parse<T: ProductId>(input: &str) -> Result<Vec<ProductInfo<T>>, ParseError> {
+    let line = input.lines().next().ok_or_else(|| {
+        let e = ProductParsingError::EmptyFile {
+            raw: input.to_string(),
+        };
+        ParseError::whole_line(e, 0, "")
+    })?;
+    line.split(',')
+        .map(|product| {
+            product.parse::<ProductInfo<T>>().map_err(|e| {
+                let part = match (&e, product.trim().split_once('-')) {
+                    (ProductParsingError::InvalidRange { raw }, Some((lower, upper))) => {
+                        [lower, upper]
+                            .into_iter()
+                            .find(|p| p == raw)
+                            .unwrap_or(product)
+                    }
+                    _ => product,
+                };
+                ParseError::at(&e, 0, line, part)
+            })
+        })
+        .collect()
+}
+
+pub fn read<T: ProductId>(reader: impl BufRead) -> Result<Vec<ProductInfo<T>>, Box<dyn Error>> {
+    Ok(parse(&read_input(reader)?)?)
+}
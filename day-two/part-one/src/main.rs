@@ -36,54 +36,12 @@ Your job is to find all of the invalid IDs that appear in the given ranges. In t
 Adding up all the invalid IDs in this example produces 1227775554.
 */
 
-mod products;
-use products::{ProductInfo, ProductParsingError};
-use rayon::prelude::*;
-use std::{
-    env,
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-fn is_invalid_id(id: u64) -> bool {
-    let num_digits: u32 = id.checked_ilog10().unwrap_or(0) + 1;
-    if !num_digits.is_multiple_of(2) {
-        return false;
-    }
-
-    let base = 10u64.pow(num_digits / 2);
-    let (first, second) = (id / base, id % base);
-    first == second
-}
-
-fn calculate_invalid_id_sum(products: Vec<ProductInfo>) -> u64 {
-    products
-        .par_iter()
-        .flat_map(|p| p.lower_id..p.upper_id)
-        .filter(|id| is_invalid_id(*id))
-        .sum::<u64>()
-}
-fn read_file(file_path: &str) -> Result<Vec<ProductInfo>, Box<dyn Error>> {
-    let h_file = File::open(file_path)?;
-    let reader = BufReader::new(h_file);
-    let lines: Vec<String> = reader.lines().collect::<Result<Vec<String>, _>>()?;
-    match lines.first() {
-        Some(line) => line
-            .split(',')
-            .map(|p| p.to_string().parse::<ProductInfo>())
-            .collect(),
-        None => Err(ProductParsingError::EmptyFile {
-            raw: file_path.to_string(),
-        }),
-    }
-    .map_err(|e: ProductParsingError| -> Box<dyn Error> { Box::new(e) })
-}
+use aoc2pt1::{cli, products::RepetitionRule};
+use std::error::Error;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let path = env::args().nth(1).expect("usage: aoc2pt1 <input-file>");
-    let products: Vec<ProductInfo> = read_file(&path)?;
-    let total = calculate_invalid_id_sum(products);
-    println!("final = {total}");
-    Ok(())
+    cli::main(
+        RepetitionRule::twice(),
+        "usage: aoc2pt1 [--exactly <k> | --at-least <k> | --at-most <k> | --any-of <k,...>] [--base <2-36>] <input-file | - | --input-str <input>>",
+    )
 }
@@ -1,10 +1,23 @@
+use crate::id::{ProductId, digits_of};
+use crate::invalid::InvalidIds;
+use std::collections::BTreeSet;
 use std::error::Error;
 use std::fmt;
 use std::str::FromStr;
 
-pub struct ProductInfo {
-    pub lower_id: u64,
-    pub upper_id: u64,
+/// A range of product IDs, held as `u64` unless a wider [`ProductId`] is
+/// asked for.
+#[derive(Debug, Clone, Copy)]
+pub struct ProductInfo<T = u64> {
+    pub lower_id: T,
+    pub upper_id: T,
+}
+
+impl<T: ProductId> ProductInfo<T> {
+    /// The IDs in the range that `rule` finds invalid, lazily and in order.
+    pub fn invalid_ids(&self, rule: &RepetitionRule) -> InvalidIds<T> {
+        InvalidIds::new(self, rule)
+    }
 }
 
 #[derive(Debug)]
@@ -24,7 +37,7 @@ impl fmt::Display for ProductParsingError {
                 write!(f, "Empty product (raw: {:?})", raw)
             }
             ProductParsingError::InvalidRange { raw } => {
-                write!(f, "Empty product (raw: {:?})", raw)
+                write!(f, "Invalid range (raw: {:?})", raw)
             }
         }
     }
@@ -32,7 +45,7 @@ impl fmt::Display for ProductParsingError {
 
 impl Error for ProductParsingError {}
 
-impl FromStr for ProductInfo {
+impl<T: ProductId> FromStr for ProductInfo<T> {
     type Err = ProductParsingError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
@@ -44,21 +57,133 @@ impl FromStr for ProductInfo {
         let (lower_raw, upper_raw) = s
             .split_once('-')
             .ok_or(ProductParsingError::InvalidRange { raw: raw.clone() })?;
-        let lower: u64 =
-            lower_raw
-                .parse::<u64>()
-                .map_err(|_| ProductParsingError::InvalidRange {
-                    raw: lower_raw.to_string(),
-                })?;
-        let upper: u64 =
-            upper_raw
-                .parse::<u64>()
-                .map_err(|_| ProductParsingError::InvalidRange {
-                    raw: upper_raw.to_string(),
-                })?;
+        // Only plain digits, since `T::from_str` may take more, such as a
+        // leading `+` or `_` separators, and each `T` would take different
+        // inputs.
+        let id = |raw: &str| {
+            raw.bytes()
+                .all(|b| b.is_ascii_digit())
+                .then(|| raw.parse::<T>().ok())
+                .flatten()
+                .ok_or_else(|| ProductParsingError::InvalidRange {
+                    raw: raw.to_string(),
+                })
+        };
+        let lower = id(lower_raw)?;
+        let upper = id(upper_raw)?;
         Ok(Self {
             lower_id: lower,
             upper_id: upper,
         })
     }
 }
+
+/// How many times over a chunk of digits must repeat to make up an ID for
+/// the ID to be invalid. Counts below two are never allowed, since every ID
+/// is itself repeated once.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Repeats {
+    Exactly(usize),
+    AtLeast(usize),
+    AtMost(usize),
+    AnyOf(BTreeSet<usize>),
+}
+
+impl Repeats {
+    pub fn allows(&self, count: usize) -> bool {
+        count >= 2
+            && match self {
+                Repeats::Exactly(k) => count == *k,
+                Repeats::AtLeast(k) => count >= *k,
+                Repeats::AtMost(k) => count <= *k,
+                Repeats::AnyOf(counts) => counts.contains(&count),
+            }
+    }
+}
+
+/// Which IDs are invalid: those whose digits in `base` are one chunk
+/// repeated an allowed number of times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RepetitionRule {
+    repeats: Repeats,
+    base: u32,
+}
+
+#[derive(Debug)]
+pub enum RepetitionRuleError {
+    InvalidBase { raw: String },
+    InvalidRepeatCount { raw: String },
+}
+
+impl fmt::Display for RepetitionRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepetitionRuleError::InvalidBase { raw } => {
+                write!(f, "Invalid base, expected 2 to 36 (raw: {:?})", raw)
+            }
+            RepetitionRuleError::InvalidRepeatCount { raw } => {
+                write!(
+                    f,
+                    "Invalid repeat count, expected 2 or more (raw: {:?})",
+                    raw
+                )
+            }
+        }
+    }
+}
+
+impl Error for RepetitionRuleError {}
+
+impl RepetitionRule {
+    pub fn new(repeats: Repeats, base: u32) -> Result<Self, RepetitionRuleError> {
+        if !(2..=36).contains(&base) {
+            return Err(RepetitionRuleError::InvalidBase {
+                raw: base.to_string(),
+            });
+        }
+        let counts = match &repeats {
+            Repeats::Exactly(k) | Repeats::AtLeast(k) | Repeats::AtMost(k) => vec![*k],
+            Repeats::AnyOf(counts) if counts.is_empty() => vec![0],
+            Repeats::AnyOf(counts) => counts.iter().copied().collect(),
+        };
+        if let Some(k) = counts.into_iter().find(|k| *k < 2) {
+            return Err(RepetitionRuleError::InvalidRepeatCount { raw: k.to_string() });
+        }
+        Ok(Self { repeats, base })
+    }
+
+    /// Part one's rule: a decimal chunk repeated exactly twice.
+    pub fn twice() -> Self {
+        Self {
+            repeats: Repeats::Exactly(2),
+            base: 10,
+        }
+    }
+
+    /// Part two's rule: a decimal chunk repeated at least twice.
+    pub fn at_least_twice() -> Self {
+        Self {
+            repeats: Repeats::AtLeast(2),
+            base: 10,
+        }
+    }
+
+    pub fn repeats(&self) -> &Repeats {
+        &self.repeats
+    }
+
+    pub fn base(&self) -> u32 {
+        self.base
+    }
+
+    /// Whether `id` is invalid, checked digit by digit.
+    pub fn is_invalid<T: ProductId>(&self, id: &T) -> bool {
+        let digits = digits_of(id, self.base);
+        (2..=digits.len())
+            .filter(|count| digits.len().is_multiple_of(*count) && self.repeats.allows(*count))
+            .any(|count| {
+                let k = digits.len() / count;
+                digits.chunks(k).all(|chunk| chunk == &digits[..k])
+            })
+    }
+}
@@ -0,0 +1,213 @@
+use aoc2pt1::{
+    Products, calculate_invalid_id_sum,
+    id::ProductId,
+    invalid_ids,
+    products::{ProductInfo, Repeats, RepetitionRule},
+    sum_invalid_ids,
+};
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+mod support;
+
+use support::{breaks, product, rule, scan};
+
+proptest! {
+    #[test]
+    fn matches_scanning_every_id(products in prop::collection::vec(product(), 1..5)) {
+        for product in &products {
+            let scanned = scan(product, &RepetitionRule::twice());
+            prop_assert_eq!(invalid_ids(product).collect::<Vec<_>>(), scanned);
+        }
+        let scanned: u64 = products
+            .iter()
+            .flat_map(|p| scan(p, &RepetitionRule::twice()))
+            .sum();
+        prop_assert_eq!(calculate_invalid_id_sum(&products), Ok(scanned));
+    }
+
+    #[test]
+    fn follows_any_rule(products in prop::collection::vec(product(), 1..3), rule in rule()) {
+        let scanned: u64 = products.iter().flat_map(|p| scan(p, &rule)).sum();
+        prop_assert_eq!(sum_invalid_ids(&products, &rule), Ok(scanned));
+        let first = products[0].lower_id;
+        prop_assert_eq!(rule.is_invalid(&first), breaks(&rule, first));
+    }
+
+    #[test]
+    fn walks_invalid_ids_lazily(product in product(), rule in rule(), skip in 0usize..4, probe in 0u64..2_000) {
+        let scanned = scan(&product, &rule);
+        let ids = product.invalid_ids(&rule);
+        prop_assert_eq!(&ids.clone().collect::<Vec<_>>(), &scanned);
+        prop_assert_eq!(ids.count_remaining(), scanned.len() as u64);
+        prop_assert_eq!(ids.clone().count(), scanned.len());
+        prop_assert_eq!(ids.total(), Some(scanned.iter().sum::<u64>()));
+        let probe = product.lower_id + probe;
+        prop_assert_eq!(ids.contains(&probe), scanned.contains(&probe));
+
+        let mut ids = ids;
+        prop_assert_eq!(ids.nth(skip), scanned.get(skip).copied());
+        let rest = scanned.get(skip + 1..).unwrap_or_default();
+        prop_assert_eq!(ids.count_remaining(), rest.len() as u64);
+        prop_assert_eq!(ids.collect::<Vec<_>>(), rest);
+    }
+
+    #[test]
+    fn agrees_across_widths(products in prop::collection::vec(product(), 1..5)) {
+        let narrow = calculate_invalid_id_sum(&products).unwrap();
+        let wide: Vec<ProductInfo<u128>> = products
+            .iter()
+            .map(|p| ProductInfo { lower_id: p.lower_id.into(), upper_id: p.upper_id.into() })
+            .collect();
+        let big: Vec<ProductInfo<BigUint>> = products
+            .iter()
+            .map(|p| ProductInfo { lower_id: p.lower_id.into(), upper_id: p.upper_id.into() })
+            .collect();
+        prop_assert_eq!(calculate_invalid_id_sum(&wide), Ok(u128::from(narrow)));
+        prop_assert_eq!(calculate_invalid_id_sum(&big), Ok(BigUint::from(narrow)));
+        prop_assert_eq!(sum_invalid_ids(&big, &RepetitionRule::twice()), Ok(BigUint::from(narrow)));
+    }
+}
+
+#[test]
+fn reaches_twenty_digit_ids() {
+    let top = ProductInfo {
+        lower_id: 18_446_744_070_000_000_000,
+        upper_id: u64::MAX,
+    };
+    assert_eq!(
+        invalid_ids(&top).collect::<Vec<_>>(),
+        [18_446_744_071_844_674_407]
+    );
+    assert_eq!(
+        calculate_invalid_id_sum(&[top]),
+        Ok(18_446_744_071_844_674_407)
+    );
+}
+
+#[test]
+fn reports_sums_past_the_id_type() {
+    let products = [
+        ProductInfo {
+            lower_id: 11,
+            upper_id: 22,
+        },
+        ProductInfo {
+            lower_id: 1,
+            upper_id: u64::MAX,
+        },
+    ];
+    let e = calculate_invalid_id_sum(&products).unwrap_err();
+    assert_eq!(e.raw, format!("1-{}", u64::MAX));
+    let e = sum_invalid_ids(&products, &RepetitionRule::at_least_twice()).unwrap_err();
+    assert_eq!(e.raw, format!("1-{}", u64::MAX));
+}
+
+#[test]
+fn reads_ids_at_the_narrowest_width() {
+    let id: u128 = 123_456_789_012_345_123_456_789_012_345;
+    let input = format!("11-22,{}-{}", id - 5, id + 5);
+    let Products::U128(products) = Products::parse(&input).unwrap() else {
+        panic!("expected u128 IDs");
+    };
+    assert_eq!(invalid_ids(&products[1]).collect::<Vec<_>>(), [id]);
+    assert_eq!(calculate_invalid_id_sum(&products), Ok(33 + id));
+
+    // Only the 20-digit half `10^19` doubled is in range.
+    let input = format!("1{}-1{}1{}", "0".repeat(39), "0".repeat(18), "0".repeat(20));
+    let Products::Big(products) = Products::parse(&input).unwrap() else {
+        panic!("expected arbitrary-precision IDs");
+    };
+    let half = BigUint::from(10u64.pow(19));
+    let rep = half.clone() * 10u32 + 1u32;
+    assert_eq!(calculate_invalid_id_sum(&products), Ok(half * rep));
+
+    assert!(matches!(
+        Products::parse("11-22").unwrap(),
+        Products::U64(_)
+    ));
+    for input in ["1_1-22", "+11-22", "11-2_2", "11-+22"] {
+        assert!(Products::parse(input).is_err(), "{input}");
+    }
+}
+
+#[test]
+fn rejects_rules_that_flag_every_id() {
+    for repeats in [
+        Repeats::Exactly(1),
+        Repeats::AtLeast(0),
+        Repeats::AtMost(1),
+        Repeats::AnyOf([1, 2].into()),
+        Repeats::AnyOf([].into()),
+    ] {
+        assert!(RepetitionRule::new(repeats, 10).is_err());
+    }
+    assert!(RepetitionRule::new(Repeats::Exactly(2), 1).is_err());
+    assert!(RepetitionRule::new(Repeats::Exactly(2), 37).is_err());
+
+    // 0b101101 is 101 twice in binary.
+    let binary = RepetitionRule::new(Repeats::Exactly(2), 2).unwrap();
+    assert!(binary.is_invalid(&45u64));
+    assert!(!binary.is_invalid(&46u64));
+}
+
+#[test]
+fn jumps_through_thirty_digit_ranges() {
+    let lower = BigUint::from(10u32).pow(29);
+    let product = ProductInfo {
+        upper_id: lower.clone() + BigUint::from(10u32).pow(16),
+        lower_id: lower,
+    };
+    // Only the 15-digit halves `10^14` to `10^14 + 9` fit, doubled by
+    // `10^15 + 1`; every shorter period repeats past the end of the range.
+    let rep = BigUint::from(10u64.pow(15) + 1);
+    let doubled = |half: u64| (BigUint::from(10u64.pow(14)) + half) * &rep;
+    for rule in [RepetitionRule::twice(), RepetitionRule::at_least_twice()] {
+        let mut ids = product.invalid_ids(&rule);
+        assert_eq!(ids.count_remaining(), 10u32.into());
+        assert_eq!(
+            ids.total(),
+            Some((BigUint::from(10u64.pow(15)) + 45u32) * &rep)
+        );
+        assert!(ids.contains(&doubled(9)));
+        assert!(!ids.contains(&(doubled(9) + 1u32)));
+        assert_eq!(ids.nth(3), Some(doubled(3)));
+        assert_eq!(ids.next(), Some(doubled(4)));
+        assert!(!ids.contains(&doubled(4)));
+        assert_eq!(ids.last(), Some(doubled(9)));
+    }
+}
+
+// Checks the last thousand IDs `upper` fits in, which have as many digits
+// in each base as the width holds, against testing them one at a time.
+fn check_width_limit<T: ProductId>(upper: T) {
+    let lower = upper.clone() - T::from(1_000);
+    let product = ProductInfo {
+        lower_id: lower.clone(),
+        upper_id: upper.clone(),
+    };
+    for base in 2..=36 {
+        let digits = T::max_digits(base).unwrap();
+        for repeats in [Repeats::Exactly(digits), Repeats::AtLeast(2)] {
+            let rule = RepetitionRule::new(repeats, base).unwrap();
+            let scanned: Vec<T> = (0..=1_000)
+                .map(|offset| lower.clone() + T::from(offset))
+                .filter(|id| rule.is_invalid(id))
+                .collect();
+            let ids = product.invalid_ids(&rule);
+            assert_eq!(ids.count_remaining(), T::from(scanned.len() as u64));
+            assert_eq!(ids.collect::<Vec<_>>(), scanned, "base {base}");
+            let sum: BigUint = scanned.iter().map(T::to_big).sum();
+            match sum_invalid_ids(std::slice::from_ref(&product), &rule) {
+                Ok(total) => assert_eq!(total.to_big(), sum),
+                Err(_) => assert!(sum > upper.to_big()),
+            }
+        }
+    }
+}
+
+#[test]
+fn reaches_the_width_limit_in_every_base() {
+    check_width_limit(u64::MAX);
+    check_width_limit(u128::MAX);
+}
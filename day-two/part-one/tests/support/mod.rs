@@ -0,0 +1,50 @@
+//! Brute-force oracles shared by the day two tests, which check the
+//! closed-form engine against testing IDs one at a time.
+
+// Each test binary uses only some of these.
+#![allow(dead_code)]
+
+use aoc2pt1::products::{ProductInfo, Repeats, RepetitionRule};
+use proptest::prelude::*;
+
+// An ID breaks a rule when its digits in the rule's base are one block
+// repeated an allowed number of times.
+pub fn breaks(rule: &RepetitionRule, mut id: u64) -> bool {
+    let base = u64::from(rule.base());
+    let mut digits = Vec::new();
+    while digits.is_empty() || id > 0 {
+        digits.push(id % base);
+        id /= base;
+    }
+    (2..=digits.len())
+        .filter(|n| digits.len().is_multiple_of(*n) && rule.repeats().allows(*n))
+        .any(|n| {
+            digits
+                .chunks(digits.len() / n)
+                .all(|c| c == &digits[..digits.len() / n])
+        })
+}
+
+/// The IDs in `product` that break `rule`, testing every one.
+pub fn scan(product: &ProductInfo, rule: &RepetitionRule) -> Vec<u64> {
+    (product.lower_id..=product.upper_id)
+        .filter(|id| breaks(rule, *id))
+        .collect()
+}
+
+pub fn rule() -> impl Strategy<Value = RepetitionRule> {
+    let repeats = prop_oneof![
+        (2usize..6).prop_map(Repeats::Exactly),
+        (2usize..6).prop_map(Repeats::AtLeast),
+        (2usize..6).prop_map(Repeats::AtMost),
+        prop::collection::btree_set(2usize..8, 1..3).prop_map(Repeats::AnyOf),
+    ];
+    (repeats, 2u32..=36).prop_map(|(repeats, base)| RepetitionRule::new(repeats, base).unwrap())
+}
+
+pub fn product() -> impl Strategy<Value = ProductInfo> {
+    (1u64..100_000_000, 0u64..20_000).prop_map(|(lower_id, span)| ProductInfo {
+        lower_id,
+        upper_id: lower_id + span,
+    })
+}
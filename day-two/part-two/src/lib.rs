@@ -0,0 +1,12 @@
+use aoc2pt1::{
+    InvalidIdSumOverflow,
+    id::ProductId,
+    products::{ProductInfo, RepetitionRule},
+    sum_invalid_ids,
+};
+
+pub fn calculate_invalid_id_sum<T: ProductId>(
+    products: &[ProductInfo<T>],
+) -> Result<T, InvalidIdSumOverflow> {
+    sum_invalid_ids(products, &RepetitionRule::at_least_twice())
+}
@@ -25,156 +25,12 @@ Adding up all the invalid IDs in this example produces 4174379265.
 What do you get if you add up all of the invalid IDs using these new rules?
 */
 
-mod products;
-use products::{ProductInfo, ProductParsingError};
-use std::{
-    env,
-    fs::File,
-    io::{BufRead, BufReader},
-};
-
-const fn calc_multiple(factor: u32, num_digits: u32) -> u64 {
-    let min_base = 10u64.pow(factor);
-    let mut multiple = 1;
-    let mut current_base = min_base;
-    while current_base < 10u64.pow(num_digits) {
-        multiple += current_base;
-        current_base *= min_base;
-    }
-    multiple
-}
-
-const fn calc_factors<const N: usize>(d: usize) -> [usize; N] {
-    let mut factors: [usize; N] = [0; N];
-    let mut i: u32 = 1;
-    while i * i <= d as u32 {
-        if d.is_multiple_of(i as usize) {
-            factors[i as usize] = i as usize;
-            if i * i != d as u32 {
-                factors[(d as u32 / i) as usize] = d / i as usize;
-            }
-        }
-        i += 1;
-    }
-    factors
-}
-
-const fn gen_multiples<const N: usize>() -> [[u64; N]; N] {
-    let mut multiples: [[u64; N]; N] = [[0; N]; N];
-    let mut i: usize = 0;
-    while i < N {
-        let factors: [usize; N] = calc_factors(i);
-        let mut j: usize = 0;
-        while j < N {
-            if factors[j] == 0 {
-                j += 1;
-                continue;
-            }
-            multiples[i][j] = calc_multiple(j as u32, i as u32);
-            j += 1;
-        }
-        i += 1;
-    }
-    multiples
-}
-
-const fn gen_factors<const N: usize>() -> [[usize; N]; N] {
-    let mut factors: [[usize; N]; N] = [[0; N]; N];
-    let mut i: usize = 0;
-    while i < N {
-        factors[i] = calc_factors(i);
-        i += 1;
-    }
-    factors
-}
-
-const fn gen_powers<const N: usize>() -> [u64; N] {
-    let mut bases: [u64; N] = [0; N];
-    let mut i: usize = 0;
-    while i < N {
-        bases[i] = 10u64.pow(i as u32);
-        i += 1;
-    }
-    bases
-}
-
-const FACTORS: [[usize; 19]; 19] = gen_factors::<19>();
-const POW10: [u64; 19] = gen_powers::<19>();
-const REP: [[u64; 19]; 19] = gen_multiples::<19>();
-
-#[inline]
-fn num_digits(n: u64) -> u32 {
-    n.checked_ilog10().unwrap_or(0) + 1
-}
-
-fn sum_invalid_in_range(low: u64, high: u64) -> u64 {
-    let min_d = num_digits(low);
-    let max_d = num_digits(high.saturating_sub(1));
-
-    let mut vals: Vec<u64> = Vec::<u64>::with_capacity(1000);
-    vals.extend((min_d..=max_d).flat_map(|d| {
-        let d_us = d as usize;
-        let p = ProductInfo {
-            lower_id: low.max(POW10[d_us - 1]),
-            upper_id: high.min(POW10[d_us]),
-        };
-        FACTORS[d_us]
-            .iter()
-            .filter(|&&f| f != 0)
-            .filter_map(move |&k| {
-                if k >= d_us {
-                    return None;
-                }
-
-                let rep = REP[d_us][k];
-
-                let mut chunk_lo = p.lower_id.div_ceil(rep);
-                let mut chunk_hi = (p.upper_id - 1) / rep;
-
-                let k_lo = POW10[k - 1];
-                let k_hi = POW10[k] - 1;
-
-                chunk_lo = chunk_lo.max(k_lo);
-                chunk_hi = chunk_hi.min(k_hi);
-
-                if chunk_lo > chunk_hi {
-                    return None;
-                }
-
-                Some((chunk_lo..=chunk_hi).map(move |chunk| rep * chunk))
-            })
-            .flatten()
-    }));
-    vals.dedup();
-    vals.into_iter().sum::<u64>()
-}
-
-fn calculate_invalid_id_sum(products: Vec<ProductInfo>) -> u64 {
-    products
-        .iter()
-        .map(|p| sum_invalid_in_range(p.lower_id, p.upper_id))
-        .sum()
-}
-
-fn read_file(file_path: &str) -> Result<Vec<ProductInfo>, ProductParsingError> {
-    let h_file = File::open(file_path)?;
-    let mut reader = BufReader::new(h_file);
-    let mut line = String::new();
-    let bytes = reader.read_line(&mut line)?;
-    if bytes == 0 {
-        return Err(ProductParsingError::EmptyFile {
-            raw: file_path.to_string(),
-        });
-    }
-    line.split(',')
-        .map(|p| p.parse::<ProductInfo>())
-        .collect::<Result<Vec<_>, _>>()
-}
-
-fn main() -> Result<(), ProductParsingError> {
-    let path = env::args().nth(1).expect("usage: aoc2pt2 <input-file>");
-    let products: Vec<ProductInfo> = read_file(&path)?;
-    let total = calculate_invalid_id_sum(products);
-    println!("final = {total}");
-    Ok(())
+use aoc2pt1::{cli, products::RepetitionRule};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    cli::main(
+        RepetitionRule::at_least_twice(),
+        "usage: aoc2pt2 [--exactly <k> | --at-least <k> | --at-most <k> | --any-of <k,...>] [--base <2-36>] <input-file | - | --input-str <input>>",
+    )
 }
@@ -0,0 +1,61 @@
+#[path = "../../part-one/tests/support/mod.rs"]
+mod support;
+
+use aoc2pt1::products::{ProductInfo, RepetitionRule};
+use aoc2pt2::calculate_invalid_id_sum;
+use num_bigint::BigUint;
+use proptest::prelude::*;
+use support::{product, scan};
+
+fn scan_every_id(products: &[ProductInfo]) -> u64 {
+    products
+        .iter()
+        .flat_map(|p| scan(p, &RepetitionRule::at_least_twice()))
+        .sum()
+}
+
+proptest! {
+    #[test]
+    fn matches_scanning_every_id(products in prop::collection::vec(product(), 1..5)) {
+        prop_assert_eq!(calculate_invalid_id_sum(&products), Ok(scan_every_id(&products)));
+    }
+}
+
+#[test]
+fn counts_single_digit_runs_once() {
+    for digits in 2..=18 {
+        let ones = (10u64.pow(digits) - 1) / 9;
+        for digit in 1..=9 {
+            let id = digit * ones;
+            let around = ProductInfo {
+                lower_id: id - 1,
+                upper_id: id + 1,
+            };
+            assert_eq!(calculate_invalid_id_sum(&[around]), Ok(id), "{id}");
+        }
+    }
+}
+
+#[test]
+fn counts_runs_past_u64_once() {
+    for digits in 2..=40 {
+        let ones: BigUint = "1".repeat(digits).parse().unwrap();
+        for digit in 1..=9u32 {
+            let id = ones.clone() * digit;
+            let around = ProductInfo {
+                lower_id: id.clone() - 1u32,
+                upper_id: id.clone() + 1u32,
+            };
+            assert_eq!(calculate_invalid_id_sum(&[around]), Ok(id.clone()), "{id}");
+        }
+    }
+    let thirty = ProductInfo::<u128> {
+        lower_id: 10u128.pow(29),
+        upper_id: 10u128.pow(29) + 10u128.pow(15),
+    };
+    // Only the 15-digit chunk `10^14` repeated twice is in range.
+    assert_eq!(
+        calculate_invalid_id_sum(&[thirty]),
+        Ok(10u128.pow(14) * (10u128.pow(15) + 1))
+    );
+}